@@ -0,0 +1,105 @@
+use crate::DynResult;
+use crate::solution::{Answer, Part};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Known-correct answers of one day, stored in `input/dayN/answers.txt`.
+///
+/// Every line has the form `<input> <part> <answer>`, where `<input>` is the name of the input
+/// file in `input/dayN/` or in another `dayN` directory such as the input cache, and the full path
+/// for inputs that live elsewhere.
+pub struct AnswerStore {
+    day: u8,
+    path: PathBuf,
+    answers: BTreeMap<(String, Part), String>,
+}
+
+impl AnswerStore {
+    pub fn load(day: u8) -> DynResult<Self> {
+        let path = PathBuf::from(format!("input/day{day}/answers.txt"));
+        let mut answers = BTreeMap::new();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        for (line_index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let invalid_line = || AnswersFileError {
+                path: path.clone(),
+                line_number: line_index + 1,
+                line: line.to_string(),
+            };
+            let mut fields = line.splitn(3, ' ');
+            let (Some(input), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid_line().into());
+            };
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(invalid_line)?;
+            answers.insert((input.to_string(), part), answer.to_string());
+        }
+        Ok(Self { day, path, answers })
+    }
+
+    pub fn get<P: AsRef<Path>>(&self, input: P, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(self.input_key(input.as_ref()), part))
+            .map(String::as_str)
+    }
+
+    pub fn record<P: AsRef<Path>>(&mut self, input: P, part: Part, answer: &Answer) {
+        let key = (self.input_key(input.as_ref()), part);
+        self.answers.insert(key, answer.to_string());
+    }
+
+    pub fn save(&self) -> DynResult<()> {
+        let mut content = String::new();
+        for ((input, part), answer) in self.answers.iter() {
+            content.push_str(&format!("{input} {part} {answer}\n"));
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    fn input_key(&self, input: &Path) -> String {
+        let day_directory = format!("day{}", self.day);
+        match (input.parent(), input.file_name()) {
+            (Some(parent), Some(file_name)) if parent.ends_with(&day_directory) => {
+                file_name.to_string_lossy().to_string()
+            }
+            _ => input.display().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AnswersFileError {
+    path: PathBuf,
+    line_number: usize,
+    line: String,
+}
+
+impl Display for AnswersFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid line {} in {}: '{}', expected '<input> <part> <answer>'",
+            self.line_number,
+            self.path.display(),
+            self.line
+        )
+    }
+}
+
+impl Error for AnswersFileError {}
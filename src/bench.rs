@@ -0,0 +1,85 @@
+use crate::cli::Cli;
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::log::span;
+use advent_of_code_2024::parsing::{locate_error, read_input};
+use advent_of_code_2024::solution::Solver;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub fn bench(cli: &Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    for (day, solver) in solvers.iter() {
+        let _day_span = span(format!("day{day}"));
+        let path = cli.input_path(*day);
+        println!(
+            "Benchmarking Day {day} on the input {} ({} iterations)",
+            path.display(),
+            cli.iterations
+        );
+        println!(
+            "{:<8}{:>14}{:>14}{:>14}{:>14}",
+            "phase", "min", "median", "mean", "stddev"
+        );
+
+        let text = read_input(&path)?;
+        let input = solver.parse(&text).map_err(|e| locate_error(e, &path))?;
+        let mut parse_samples = vec![];
+        for _ in 0..cli.iterations {
+            let start = Instant::now();
+            black_box(solver.parse(&text)?);
+            parse_samples.push(start.elapsed());
+        }
+        print_statistics("parse", &parse_samples);
+
+        for part in cli.parts.iter() {
+            let mut part_samples = vec![];
+            for _ in 0..cli.iterations {
+                let start = Instant::now();
+                black_box(solver.solve(part, &input)?);
+                part_samples.push(start.elapsed());
+            }
+            print_statistics(&format!("part {part}"), &part_samples);
+        }
+    }
+    Ok(())
+}
+
+fn print_statistics(phase: &str, samples: &[Duration]) {
+    let statistics = Statistics::from_samples(samples);
+    println!(
+        "{:<8}{:>14.3?}{:>14.3?}{:>14.3?}{:>14.3?}",
+        phase, statistics.min, statistics.median, statistics.mean, statistics.std_dev
+    );
+}
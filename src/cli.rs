@@ -0,0 +1,294 @@
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::log::Level;
+use advent_of_code_2024::parsing::STDIN_PATH;
+use advent_of_code_2024::solution::Part;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: AdventOfCode2024 [COMMAND] [DAYS] [OPTIONS]
+
+Commands:
+  run       Solve the selected days and print the answers (default)
+  verify    Compare the answers with the known answers in input/dayN/answers.txt
+  bench     Time parsing and both parts of the selected days
+  memory    Count allocations and peak memory of parsing and both parts of the selected days
+            (needs a build with `--features alloc-stats`)
+  watch     Re-run the selected days whenever their input or example changes
+  submit    Submit the answer of one day and part, e.g. `submit 3 --part 1`
+  inspect   Describe the structure of an input file and suggest a parser for it, e.g.
+            `inspect input/day5/input.txt`
+  new-day   Generate the module and input placeholders of a new day, e.g. `new-day 7`
+
+DAYS is a comma-separated list of days and day ranges, e.g. `3`, `1,4` or `2-5`.
+If it is omitted, every solved day is run.
+
+Options:
+  -p, --part <1|2>           Only run the given part
+  -i, --input [<DAY>=]<PATH> Read the input of DAY (or of every selected day) from PATH instead
+                             of input/dayN/input.txt; `-` reads from standard input
+  -e, --example              Use input/dayN/example.txt instead of input/dayN/input.txt
+  -j, --jobs <N>             With `run`, solve the selected days in parallel on N threads and
+                             print a summary once all of them are done
+  -a, --answer <ANSWER>      With `submit`, submit ANSWER instead of solving the puzzle
+      --json                 With `run`, print the results as a JSON document
+      --stream               With `run`, read the input line by line and solve both parts in one
+                             pass, for days that support it (1 and 2)
+      --record               With `verify`, store answers that are not known yet
+  -n, --iterations <N>       With `bench`, the number of timed runs per phase (default: 10)
+      --expand-tabs <N>      Expand tabs in the inputs to tab stops every N columns
+  -q, --quiet                Only log warnings and errors, hiding the progress banners
+  -v, --verbose              Also log debug messages, or trace messages if given twice
+      --log-level <LEVEL>    Log messages up to LEVEL: error, warn, info (default), debug, trace
+  -h, --help                 Print this help
+
+Missing inputs are downloaded once and cached. Downloads and submissions are configured by the
+environment:
+  AOC_SESSION                Session token sent as cookie when downloading inputs and submitting
+  AOC_BASE_URL               Server to talk to (default: https://adventofcode.com)
+  AOC_CACHE_DIR              Directory to cache downloaded inputs in (default: input)";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Verify,
+    Bench,
+    Memory,
+    Watch,
+    Submit,
+    Inspect,
+    NewDay,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn includes(self, part: Part) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::One => part == Part::One,
+            Parts::Two => part == Part::Two,
+        }
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(move |part| self.includes(*part))
+    }
+}
+
+#[derive(Debug)]
+pub struct Cli {
+    pub command: Command,
+    pub days: Option<Vec<u8>>,
+    pub file: Option<PathBuf>,
+    pub parts: Parts,
+    pub inputs: HashMap<u8, PathBuf>,
+    pub input: Option<PathBuf>,
+    pub example: bool,
+    pub answer: Option<String>,
+    pub jobs: Option<usize>,
+    pub json: bool,
+    pub stream: bool,
+    pub record: bool,
+    pub iterations: usize,
+    pub log_level: Level,
+    pub tab_width: Option<usize>,
+    pub help: bool,
+}
+
+impl Cli {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> DynResult<Self> {
+        let mut cli = Self {
+            command: Command::Run,
+            days: None,
+            file: None,
+            parts: Parts::Both,
+            inputs: HashMap::new(),
+            input: None,
+            example: false,
+            answer: None,
+            jobs: None,
+            json: false,
+            stream: false,
+            record: false,
+            iterations: 10,
+            log_level: Level::Info,
+            tab_width: None,
+            help: false,
+        };
+        let mut args = args.into_iter().peekable();
+        match args.peek().map(String::as_str) {
+            Some("run") => {
+                args.next();
+            }
+            Some("verify") => {
+                args.next();
+                cli.command = Command::Verify;
+            }
+            Some("bench") => {
+                args.next();
+                cli.command = Command::Bench;
+            }
+            Some("memory") => {
+                args.next();
+                cli.command = Command::Memory;
+            }
+            Some("watch") => {
+                args.next();
+                cli.command = Command::Watch;
+            }
+            Some("submit") => {
+                args.next();
+                cli.command = Command::Submit;
+            }
+            Some("inspect") => {
+                args.next();
+                cli.command = Command::Inspect;
+            }
+            Some("new-day") => {
+                args.next();
+                cli.command = Command::NewDay;
+            }
+            _ => {}
+        }
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => cli.help = true,
+                "-q" | "--quiet" => cli.log_level = Level::Warn,
+                "-v" | "--verbose" if cli.log_level >= Level::Debug => cli.log_level = Level::Trace,
+                "-v" | "--verbose" => cli.log_level = Level::Debug,
+                "-vv" => cli.log_level = Level::Trace,
+                "--log-level" => cli.log_level = next_value(&mut args, &arg)?.parse()?,
+                "-e" | "--example" => cli.example = true,
+                "-a" | "--answer" => cli.answer = Some(next_value(&mut args, &arg)?),
+                "-j" | "--jobs" => {
+                    let value = next_value(&mut args, &arg)?;
+                    cli.jobs = match value.parse() {
+                        Ok(jobs) if jobs > 0 => Some(jobs),
+                        _ => return Err(CliError(format!("Invalid job count '{value}'")).into()),
+                    };
+                }
+                "--json" => cli.json = true,
+                "--stream" => cli.stream = true,
+                "--record" => cli.record = true,
+                "-n" | "--iterations" => {
+                    let value = next_value(&mut args, &arg)?;
+                    cli.iterations = match value.parse() {
+                        Ok(iterations) if iterations > 0 => iterations,
+                        _ => {
+                            return Err(
+                                CliError(format!("Invalid iteration count '{value}'")).into()
+                            );
+                        }
+                    };
+                }
+                "--expand-tabs" => {
+                    let value = next_value(&mut args, &arg)?;
+                    cli.tab_width = match value.parse() {
+                        Ok(tab_width) if tab_width > 0 => Some(tab_width),
+                        _ => return Err(CliError(format!("Invalid tab width '{value}'")).into()),
+                    };
+                }
+                "-p" | "--part" => {
+                    cli.parts = match next_value(&mut args, &arg)?.as_str() {
+                        "1" => Parts::One,
+                        "2" => Parts::Two,
+                        other => return Err(CliError(format!("Invalid part '{other}'")).into()),
+                    }
+                }
+                "-i" | "--input" => {
+                    let value = next_value(&mut args, &arg)?;
+                    match value.split_once('=') {
+                        Some((day, path)) if day.chars().all(|c| c.is_ascii_digit()) => {
+                            cli.inputs.insert(parse_day(day)?, PathBuf::from(path));
+                        }
+                        _ => cli.input = Some(PathBuf::from(value)),
+                    }
+                }
+                _ if arg.starts_with('-') && arg != STDIN_PATH => {
+                    return Err(CliError(format!("Unknown option '{arg}'")).into());
+                }
+                _ if cli.command == Command::Inspect && cli.file.is_none() => {
+                    cli.file = Some(PathBuf::from(arg));
+                }
+                _ if cli.command != Command::Inspect && cli.days.is_none() => {
+                    cli.days = Some(parse_days(&arg)?);
+                }
+                _ => return Err(CliError(format!("Unexpected argument '{arg}'")).into()),
+            }
+        }
+        Ok(cli)
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.inputs
+            .get(&day)
+            .or(self.input.as_ref())
+            .cloned()
+            .unwrap_or_else(|| {
+                if self.example {
+                    example_path(day)
+                } else {
+                    PathBuf::from(format!("input/day{day}/input.txt"))
+                }
+            })
+    }
+}
+
+pub fn example_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("input/day{day}/example.txt"))
+}
+
+fn next_value<I: Iterator<Item = String>>(args: &mut I, option: &str) -> DynResult<String> {
+    Ok(args
+        .next()
+        .ok_or_else(|| CliError(format!("Missing value for '{option}'")))?)
+}
+
+fn parse_day(day: &str) -> DynResult<u8> {
+    match day.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(CliError(format!(
+            "Invalid day '{day}', expected a number from 1 to 25"
+        ))
+        .into()),
+    }
+}
+
+fn parse_days(days: &str) -> DynResult<Vec<u8>> {
+    let mut result = vec![];
+    for part in days.split(',') {
+        if let Some((start, end)) = part.split_once('-') {
+            let (start, end) = (parse_day(start)?, parse_day(end)?);
+            if start > end {
+                return Err(CliError(format!("Invalid day range '{part}'")).into());
+            }
+            result.extend(start..=end);
+        } else {
+            result.push(parse_day(part)?);
+        }
+    }
+    result.sort();
+    result.dedup();
+    Ok(result)
+}
+
+#[derive(Debug)]
+pub struct CliError(pub String);
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for CliError {}
@@ -0,0 +1,114 @@
+use crate::DynResult;
+use crate::parsing::{NumberFormat, ParseError, parse_integers_from_str, stream_numbers};
+use crate::solution::{Answer, Solution, StreamingSolution};
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::iter;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {
+        let mut left_list = vec![];
+        let mut right_list = vec![];
+        let numbers = parse_integers_from_str(input)?;
+        for (line_index, (line, numbers)) in input.lines().zip(numbers).enumerate() {
+            let [left, right] = numbers[..] else {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return Err(ParseError::new(line_index + 1, 1, "", "Expected two numbers")
+                    .with_source_line(line)
+                    .into());
+            };
+            left_list.push(left);
+            right_list.push(right);
+        }
+        left_list.sort();
+        right_list.sort();
+        Ok((left_list, right_list))
+    }
+
+    fn part1(&self, (left_list, right_list): &Self::Input) -> DynResult<Answer> {
+        let mut first_result = 0;
+        for (left, right) in left_list.iter().zip(right_list.iter()) {
+            if left < right {
+                first_result += right - left;
+            } else {
+                first_result += left - right;
+            }
+        }
+        Ok(first_result.into())
+    }
+
+    fn part2(&self, (left_list, right_list): &Self::Input) -> DynResult<Answer> {
+        let mut second_result = 0;
+        for left_entry in left_list.iter() {
+            let right_occurrences = right_list.iter().filter(|r| *r == left_entry).count();
+            second_result += left_entry * right_occurrences as i32;
+        }
+        Ok(second_result.into())
+    }
+}
+
+/// Only keeps how often each location ID occurs in either list, which is bounded by the number of
+/// distinct IDs rather than the length of the lists.
+impl StreamingSolution for Day1 {
+    fn solve_streaming(&self, reader: &mut dyn BufRead) -> DynResult<(Answer, Answer)> {
+        let mut left_counts: BTreeMap<i64, i64> = BTreeMap::new();
+        let mut right_counts: BTreeMap<i64, i64> = BTreeMap::new();
+        for (line_index, line) in stream_numbers(reader, NumberFormat::Signed).enumerate() {
+            let (line, numbers) = line?;
+            let [left, right] = numbers[..] else {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return Err(ParseError::new(line_index + 1, 1, "", "Expected two numbers")
+                    .with_source_line(&line)
+                    .into());
+            };
+            *left_counts.entry(left).or_default() += 1;
+            *right_counts.entry(right).or_default() += 1;
+        }
+
+        let first_result: i64 = sorted_ids(&left_counts)
+            .zip(sorted_ids(&right_counts))
+            .map(|(left, right)| (left - right).abs())
+            .sum();
+        let second_result: i64 = left_counts
+            .iter()
+            .map(|(id, count)| id * count * right_counts.get(id).copied().unwrap_or(0))
+            .sum();
+        Ok((first_result.into(), second_result.into()))
+    }
+}
+
+fn sorted_ids(counts: &BTreeMap<i64, i64>) -> impl Iterator<Item = i64> + '_ {
+    counts
+        .iter()
+        .flat_map(|(id, count)| iter::repeat_n(*id, *count as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/day1/example.txt");
+
+    #[test]
+    fn streaming_matches_parsing() {
+        let input = Day1.parse(EXAMPLE).unwrap();
+        let expected = (Day1.part1(&input).unwrap(), Day1.part2(&input).unwrap());
+        assert_eq!(Day1.solve_streaming(&mut EXAMPLE.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn streaming_locates_lines_without_two_numbers() {
+        let error = Day1.solve_streaming(&mut "3   4\n4\n".as_bytes()).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.source_line.as_deref(), Some("4"));
+    }
+}
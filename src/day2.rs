@@ -1,31 +1,57 @@
 use crate::DynResult;
-use crate::parsing::parse_integers;
+use crate::parsing::{NumberFormat, parse_integers_from_str, stream_numbers};
+use crate::solution::{Answer, Solution, StreamingSolution};
 use std::cmp::Ordering;
-use std::path::Path;
-
-pub fn solve_day2<P: AsRef<Path>>(path: P) -> DynResult<()> {
-    println!(
-        "Solving Day 2 Part 1 on the input {}",
-        path.as_ref().to_str().unwrap()
-    );
-    let input = parse_integers(path)?;
-    let mut safe_report_count = 0;
-    for report in input.iter() {
-        if is_safe(report) {
-            safe_report_count += 1;
+use std::io::BufRead;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {
+        parse_integers_from_str(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> DynResult<Answer> {
+        let mut safe_report_count = 0;
+        for report in input.iter() {
+            if is_safe(report) {
+                safe_report_count += 1;
+            }
+        }
+        Ok(safe_report_count.into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> DynResult<Answer> {
+        let mut second_result = 0;
+        for report in input.iter() {
+            if is_safe_with_dampener(report) {
+                second_result += 1;
+            }
         }
+        Ok(second_result.into())
     }
-    println!("The number of safe reports is: {}", safe_report_count);
+}
 
-    println!("Solving Day 2 Part 2 on the same input");
-    let mut second_result = 0;
-    for report in input.iter() {
-        if is_safe_with_dampener(report) {
-            second_result += 1;
+impl StreamingSolution for Day2 {
+    fn solve_streaming(&self, reader: &mut dyn BufRead) -> DynResult<(Answer, Answer)> {
+        let mut safe_report_count = 0;
+        let mut second_result = 0;
+        for report in stream_numbers(reader, NumberFormat::Signed) {
+            let (_, report): (_, Vec<i32>) = report?;
+            if report.is_empty() {
+                continue;
+            }
+            if is_safe(&report) {
+                safe_report_count += 1;
+            }
+            if is_safe_with_dampener(&report) {
+                second_result += 1;
+            }
         }
+        Ok((safe_report_count.into(), second_result.into()))
     }
-    println!("The solution is: {}", second_result);
-    Ok(())
 }
 
 fn is_safe(report: &[i32]) -> bool {
@@ -39,19 +65,19 @@ fn is_safe(report: &[i32]) -> bool {
 fn is_iterator_safe<'a, T: Iterator<Item = &'a i32> + Clone>(report: T) -> bool {
     for (cur, prev) in report.clone().skip(1).zip(report) {
         let step = cur - prev;
-        if step < 1 || step > 3 {
+        if !(1..=3).contains(&step) {
             return false;
         }
     }
     true
 }
 
-fn is_safe_with_dampener(report: &Vec<i32>) -> bool {
+fn is_safe_with_dampener(report: &[i32]) -> bool {
     if is_safe(report) {
         return true;
     }
     for i in 0..report.len() {
-        let mut report_clone = report.clone();
+        let mut report_clone = report.to_vec();
         report_clone.remove(i);
         if is_safe(&report_clone) {
             return true;
@@ -59,3 +85,17 @@ fn is_safe_with_dampener(report: &Vec<i32>) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/day2/example.txt");
+
+    #[test]
+    fn streaming_matches_parsing() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+        let expected = (Day2.part1(&input).unwrap(), Day2.part2(&input).unwrap());
+        assert_eq!(Day2.solve_streaming(&mut EXAMPLE.as_bytes()).unwrap(), expected);
+    }
+}
@@ -1,56 +1,62 @@
 use crate::DynResult;
-use crate::parsing::process_input_for_matches;
+use crate::debug;
+use crate::parsing::find_matches;
+use crate::parsing::template::LineTemplate;
+use crate::solution::{Answer, Solution};
 use once_cell::sync::Lazy;
-use regex::Regex;
-use std::path::Path;
 
-const MUL_INSTRUCTION: &str = r"mul\((\d*),(\d*)\)";
-static MUL_MATCHER: Lazy<Regex> = Lazy::new(|| Regex::new(MUL_INSTRUCTION).unwrap());
+const MUL_INSTRUCTION: &str = r"mul\(\d{1,3},\d{1,3}\)";
+static MUL_TEMPLATE: Lazy<LineTemplate> = Lazy::new(|| LineTemplate::new("mul({},{})"));
 const DO_INSTRUCTION: &str = r"do\(\)";
-static DO_MATCHER: Lazy<Regex> = Lazy::new(|| Regex::new(DO_INSTRUCTION).unwrap());
 const DONT_INSTRUCTION: &str = r"don't\(\)";
-static DONT_MATCHER: Lazy<Regex> = Lazy::new(|| Regex::new(DONT_INSTRUCTION).unwrap());
 
-pub fn solve_day3<P: AsRef<Path>>(path: P) -> DynResult<()> {
-    println!(
-        "Solving Day 3 Part 1 on the input {}",
-        path.as_ref().to_str().unwrap()
-    );
-    let input = process_input_for_matches(&path, MUL_INSTRUCTION)?;
-    let mut first_result = 0;
-    for instruction in input.iter() {
-        first_result += compute_multiplication(instruction)?;
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> DynResult<Answer> {
+        let instructions = find_matches(input, MUL_INSTRUCTION)?;
+        let mut first_result = 0;
+        for instruction in instructions.iter() {
+            debug!("Matched instruction {instruction}");
+            first_result += compute_multiplication(instruction);
+        }
+        Ok(first_result.into())
     }
-    println!("The solution is: {}", first_result);
 
-    println!("Solving Day 3 Part 2 on the same input");
-    let input = process_input_for_matches(
-        path,
-        &format!(
-            "{}|{}|{}",
-            MUL_INSTRUCTION, DO_INSTRUCTION, DONT_INSTRUCTION
-        ),
-    )?;
-    let mut second_result = 0;
-    let mut is_mul_enabled = true;
-    for instruction in input.iter() {
-        if DO_MATCHER.is_match(instruction) {
-            is_mul_enabled = true;
-        } else if DONT_MATCHER.is_match(instruction) {
-            is_mul_enabled = false;
-        } else if is_mul_enabled {
-            if MUL_MATCHER.is_match(instruction) {
-                second_result += compute_multiplication(instruction)?;
+    fn part2(&self, input: &Self::Input) -> DynResult<Answer> {
+        let instructions = find_matches(
+            input,
+            &format!(
+                "{}|{}|{}",
+                MUL_INSTRUCTION, DO_INSTRUCTION, DONT_INSTRUCTION
+            ),
+        )?;
+        let mut second_result = 0;
+        let mut is_mul_enabled = true;
+        for instruction in instructions.iter() {
+            debug!("Matched instruction {instruction}, multiplications enabled: {is_mul_enabled}");
+            match instruction.as_str() {
+                "do()" => is_mul_enabled = true,
+                "don't()" => is_mul_enabled = false,
+                _ if is_mul_enabled => second_result += compute_multiplication(instruction),
+                _ => {}
             }
         }
+        Ok(second_result.into())
     }
-    println!("The solution is: {}", second_result);
-    Ok(())
 }
 
-fn compute_multiplication(instruction: &str) -> DynResult<i32> {
-    let captures = MUL_MATCHER.captures(instruction).unwrap();
-    let first_number: i32 = captures.get(1).unwrap().as_str().parse()?;
-    let second_number: i32 = captures.get(2).unwrap().as_str().parse()?;
-    Ok(first_number * second_number)
+/// Every match of [`MUL_INSTRUCTION`] consists of two numbers of at most three digits, so it always
+/// fits [`MUL_TEMPLATE`].
+fn compute_multiplication(instruction: &str) -> i32 {
+    let (first_number, second_number): (i32, i32) = MUL_TEMPLATE
+        .parse_line(instruction, 1)
+        .expect("Matches of MUL_INSTRUCTION are valid multiplications");
+    first_number * second_number
 }
@@ -1,4 +1,6 @@
 use super::*;
+use crate::parsing::ParseError;
+use crate::parsing::grid::parse_grid;
 
 #[derive(Debug)]
 pub struct MatrixBase<T> {
@@ -37,7 +39,7 @@ impl<T: Clone> Matrix<T> for MatrixBase<T> {
     }
 
     fn get(&self, x: usize, y: usize) -> Option<T> {
-        if x >= self.rows || y >= self.columns {
+        if x >= self.columns || y >= self.rows {
             None
         } else {
             self.inner.get(y * self.columns + x).cloned()
@@ -54,27 +56,10 @@ impl<T: Clone> MutMatrix<T> for MatrixBase<T> {
 }
 
 impl TryFrom<String> for MatrixBase<Char> {
-    type Error = CharAsAsciiError;
+    type Error = ParseError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let mut rows = None;
-        let mut columns = 0;
-        let mut chars = vec![];
-        for line in value.lines() {
-            columns += 1;
-            let mut cur_chars = vec![];
-            for c in line.chars() {
-                cur_chars.push(c.as_ascii().ok_or(CharAsAsciiError(c))?);
-            }
-            if let Some(rows) = &rows {
-                assert_eq!(*rows, cur_chars.len());
-            } else {
-                rows = Some(cur_chars.len());
-            }
-            chars.append(&mut cur_chars);
-        }
-
-        Ok(MatrixBase::new(rows.unwrap(), columns, chars))
+        parse_grid(&value, char_as_ascii)
     }
 }
 
@@ -18,7 +18,7 @@ impl<'a, M, T, IT> IndexedWalk<'a, M, T, IT> {
         Self {
             matrix,
             indices,
-            phantom_t: PhantomData::default()
+            phantom_t: PhantomData
         }
     }
 }
@@ -20,7 +20,7 @@ impl<'a, M, T, IT> Walk<'a, M, T, IT> {
         Self {
             matrix,
             indices,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
@@ -115,7 +115,7 @@ impl<'a, M, T> Rows<'a, M, T> {
         Self {
             matrix,
             current_row: 0,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
@@ -144,7 +144,7 @@ impl<'a, M, T> Columns<'a, M, T> {
         Self {
             matrix,
             current_column: 0,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
@@ -176,7 +176,7 @@ where
         Self {
             matrix,
             current_diagonal: -(matrix.row_count() as i32) + 1,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
@@ -208,7 +208,7 @@ where
         Self {
             matrix,
             current_diagonal: -(matrix.row_count() as i32) + 1,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
@@ -253,7 +253,7 @@ where
             convolve_height: convolve_height as isize,
             current_row: 0,
             current_column: 0,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
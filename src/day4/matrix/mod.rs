@@ -10,8 +10,8 @@ use self::iter::indexed::IndexedFullWalk;
 pub mod base;
 pub mod bool;
 pub mod sparse;
-mod iter;
-mod view;
+pub mod iter;
+pub mod view;
 
 pub trait Matrix<T>: Sized {
     fn row_count(&self) -> usize;
@@ -24,11 +24,11 @@ pub trait Matrix<T>: Sized {
         self.row_count() * self.column_count()
     }
 
-    fn iter(&self) -> FullWalk<Self, T> {
+    fn iter(&self) -> FullWalk<'_, Self, T> {
         FullWalk::new(self, AllIndices::new(self.column_count()))
     }
 
-    fn indexed_iter(&self) -> IndexedFullWalk<Self, T> {
+    fn indexed_iter(&self) -> IndexedFullWalk<'_, Self, T> {
         IndexedFullWalk::new(self, AllIndices::new(self.column_count()))
     }
 
@@ -38,7 +38,7 @@ pub trait Matrix<T>: Sized {
         x_end: isize,
         y_start: isize,
         y_end: isize,
-    ) -> Option<MatrixView<Self, T>> {
+    ) -> Option<MatrixView<'_, Self, T>> {
         if x_start < 0
             || x_end > self.column_count() as isize
             || y_start < 0
@@ -54,7 +54,7 @@ pub trait Matrix<T>: Sized {
         }
     }
 
-    fn walk<IT>(&self, indices: IT) -> Walk<Self, T, IT> {
+    fn walk<IT>(&self, indices: IT) -> Walk<'_, Self, T, IT> {
         Walk::new(self, indices)
     }
 
@@ -64,14 +64,14 @@ pub trait Matrix<T>: Sized {
         cur_y: usize,
         step_x: i32,
         step_y: i32,
-    ) -> ArithmeticWalk<Self, T> {
+    ) -> ArithmeticWalk<'_, Self, T> {
         Walk::new(
             self,
             ArithmeticIndices::new(cur_x as i32, cur_y as i32, step_x, step_y),
         )
     }
 
-    fn row(&self, row_index: usize) -> Option<ArithmeticWalk<Self, T>> {
+    fn row(&self, row_index: usize) -> Option<ArithmeticWalk<'_, Self, T>> {
         if row_index >= self.row_count() {
             None
         } else {
@@ -79,11 +79,11 @@ pub trait Matrix<T>: Sized {
         }
     }
 
-    fn rows(&self) -> Rows<Self, T> {
+    fn rows(&self) -> Rows<'_, Self, T> {
         Rows::new(self)
     }
 
-    fn column(&self, column_index: usize) -> Option<ArithmeticWalk<Self, T>> {
+    fn column(&self, column_index: usize) -> Option<ArithmeticWalk<'_, Self, T>> {
         if column_index >= self.column_count() {
             None
         } else {
@@ -91,11 +91,11 @@ pub trait Matrix<T>: Sized {
         }
     }
 
-    fn columns(&self) -> Columns<Self, T> {
+    fn columns(&self) -> Columns<'_, Self, T> {
         Columns::new(self)
     }
 
-    fn down_diagonal(&self, diagonal_index: i32) -> Option<ArithmeticWalk<Self, T>> {
+    fn down_diagonal(&self, diagonal_index: i32) -> Option<ArithmeticWalk<'_, Self, T>> {
         if diagonal_index <= -(self.row_count() as i32)
             || diagonal_index >= self.column_count() as i32
         {
@@ -107,11 +107,11 @@ pub trait Matrix<T>: Sized {
         }
     }
 
-    fn down_diagonals(&self) -> DownDiagonals<Self, T> {
+    fn down_diagonals(&self) -> DownDiagonals<'_, Self, T> {
         DownDiagonals::new(self)
     }
 
-    fn up_diagonal(&self, diagonal_index: i32) -> Option<ArithmeticWalk<Self, T>> {
+    fn up_diagonal(&self, diagonal_index: i32) -> Option<ArithmeticWalk<'_, Self, T>> {
         if diagonal_index <= -(self.row_count() as i32)
             || diagonal_index >= self.column_count() as i32
         {
@@ -124,15 +124,15 @@ pub trait Matrix<T>: Sized {
                 -1,
             ))
         } else {
-            Some(self.arithmetic_walk(diagonal_index as usize, self.column_count() - 1, 1, -1))
+            Some(self.arithmetic_walk(diagonal_index as usize, self.row_count() - 1, 1, -1))
         }
     }
 
-    fn up_diagonals(&self) -> UpDiagonals<Self, T> {
+    fn up_diagonals(&self) -> UpDiagonals<'_, Self, T> {
         UpDiagonals::new(self)
     }
 
-    fn word_search(&self) -> WordSearch<Self, T> {
+    fn word_search(&self) -> WordSearch<'_, Self, T> {
         self.rows()
             .chain(self.columns())
             .chain(self.down_diagonals())
@@ -143,7 +143,7 @@ pub trait Matrix<T>: Sized {
         &self,
         convolve_width: usize,
         convolve_height: usize,
-    ) -> ConvolveIter<Self, T> {
+    ) -> ConvolveIter<'_, Self, T> {
         ConvolveIter::new(self, convolve_width, convolve_height)
     }
 
@@ -176,6 +176,10 @@ pub trait MutMatrix<T>: Matrix<T> {
     fn set(&mut self, x: usize, y: usize, value: T);
 }
 
+pub fn char_as_ascii(c: char) -> Result<Char, CharAsAsciiError> {
+    c.as_ascii().ok_or(CharAsAsciiError(c))
+}
+
 #[derive(Debug)]
 pub struct CharAsAsciiError(char);
 
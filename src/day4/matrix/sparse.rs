@@ -89,7 +89,7 @@ impl<T: Clone> Matrix<T> for SparseMatrix<T> {
     }
 
     fn get(&self, x: usize, y: usize) -> Option<T> {
-        if x >= self.rows || y >= self.columns {
+        if x >= self.columns || y >= self.rows {
             None
         } else {
             if let Some(value) = self.matrix.get(&(x, y)) {
@@ -36,14 +36,14 @@ where
         assert!(x_range.end >= 0);
         assert!(x_range.end <= matrix.column_count() as isize);
         assert!(y_range.start >= 0);
-        assert!(y_range.start <= matrix.column_count() as isize);
+        assert!(y_range.start <= matrix.row_count() as isize);
         assert!(y_range.end >= 0);
-        assert!(y_range.end <= matrix.column_count() as isize);
+        assert!(y_range.end <= matrix.row_count() as isize);
         Self {
             matrix,
             x_range,
             y_range,
-            phantom_t: PhantomData::default(),
+            phantom_t: PhantomData,
         }
     }
 }
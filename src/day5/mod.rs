@@ -1,50 +1,64 @@
 use crate::DynResult;
 use crate::day4::matrix::{Matrix, MutMatrix, bool::BoolMatrix};
-use crate::parsing::parse_integers;
-use std::path::Path;
+use crate::parsing::template::LineTemplate;
+use crate::parsing::{NumberFormat, parse_numbers_from_str, split_sections_exact};
+use crate::solution::{Answer, Solution};
+use once_cell::sync::Lazy;
 
-pub fn solve_day5<P: AsRef<Path>>(path: P) -> DynResult<()> {
-    println!(
-        "Solving Day 5 on the input {}",
-        path.as_ref().to_str().unwrap()
-    );
-    let input = parse_integers(path)?;
-    let node_count = (*input.iter().flatten().max().unwrap() + 1) as usize;
-    let mut split_input = input.split(Vec::is_empty);
-    let page_ordering_rules: Vec<(usize, usize)> = split_input
-        .next()
-        .unwrap()
-        .iter()
-        .cloned()
-        .map(|l| (l[0] as usize, l[1] as usize))
-        .collect();
-    let updates: Vec<Vec<usize>> = split_input
-        .next()
-        .unwrap()
-        .iter()
-        .cloned()
-        .map(|l| l.into_iter().map(|i| i as usize).collect())
-        .collect();
-    let mut page_ordering_graph = BoolMatrix::new(node_count, node_count);
-    for (source, dest) in page_ordering_rules.iter() {
-        page_ordering_graph.set(*dest, *source, true);
+static RULE_TEMPLATE: Lazy<LineTemplate> = Lazy::new(|| LineTemplate::new("{}|{}"));
+
+pub struct Day5;
+
+pub struct PrintQueue {
+    page_ordering_graph: BoolMatrix,
+    updates: Vec<Vec<usize>>,
+}
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {
+        let [rules, updates] = split_sections_exact(input)?;
+        let page_ordering_rules: Vec<(usize, usize)> =
+            rules.parse(|text| RULE_TEMPLATE.parse_all(text))?;
+        let updates: Vec<Vec<usize>> =
+            updates.parse(|text| parse_numbers_from_str(text, NumberFormat::Unsigned))?;
+        let node_count = page_ordering_rules
+            .iter()
+            .flat_map(|(source, dest)| [*source, *dest])
+            .chain(updates.iter().flatten().copied())
+            .max()
+            .map_or(0, |max| max + 1);
+        let mut page_ordering_graph = BoolMatrix::new(node_count, node_count);
+        for (source, dest) in page_ordering_rules.iter() {
+            page_ordering_graph.set(*dest, *source, true);
+        }
+        Ok(PrintQueue {
+            page_ordering_graph,
+            updates,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> DynResult<Answer> {
+        let first_result = input
+            .updates
+            .iter()
+            .filter(|u| is_update_correctly_ordered(u, &input.page_ordering_graph))
+            .fold(0usize, |acc, u| acc + u[u.len() / 2]);
+        Ok(first_result.into())
     }
-    let first_result = updates
-        .iter()
-        .filter(|u| is_update_correctly_ordered(*u, &page_ordering_graph))
-        .fold(0usize, |acc, u| acc + u[u.len() / 2]);
-    println!("The solution to part 1 is: {first_result}");
 
-    let mut updates = updates;
-    let second_result = updates
-        .iter_mut()
-        .filter(|u| !is_update_correctly_ordered(*u, &page_ordering_graph))
-        .fold(0usize, |acc, u| {
-            sort_update(u, &page_ordering_graph);
-            acc + u[u.len() / 2]
-        });
-    println!("The solution to part 2 is: {second_result}");
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> DynResult<Answer> {
+        let mut updates = input.updates.clone();
+        let second_result = updates
+            .iter_mut()
+            .filter(|u| !is_update_correctly_ordered(u, &input.page_ordering_graph))
+            .fold(0usize, |acc, u| {
+                sort_update(u, &input.page_ordering_graph);
+                acc + u[u.len() / 2]
+            });
+        Ok(second_result.into())
+    }
 }
 
 fn is_update_correctly_ordered(update: &[usize], page_ordering_graph: &BoolMatrix) -> bool {
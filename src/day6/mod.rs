@@ -1,11 +1,13 @@
 use crate::DynResult;
+use crate::debug;
+use crate::solution::{Answer, Solution};
+use ndarray::Array2;
 use once_cell::sync::Lazy;
 use std::ascii::Char;
 use std::collections::HashMap;
-use std::path::Path;
 use self::patrol_tracker::StepResult;
 use self::patrol_tracker::PatrolTracker;
-use crate::parsing::read_file_to_char_matrix;
+use crate::parsing::{ParseError, parse_char_matrix_from_str};
 
 mod patrol_tracker;
 
@@ -19,31 +21,65 @@ static CLOCKWISE_ROTATION: Lazy<HashMap<Char, Char>> = Lazy::new(|| {
     .into()
 });
 
-pub fn solve_day6<P: AsRef<Path>>(path: P) -> DynResult<()> {
-    println!(
-        "Solving Day 6 on the input {}",
-        path.as_ref().to_str().unwrap()
-    );
+pub struct Day6;
 
-    let map = read_file_to_char_matrix(path)?;
-    let mut patrol_tracker = PatrolTracker::new(map.clone());
-    while patrol_tracker.take_step(true) == StepResult::KeepGoing {}
-    println!("The solution to part 1 is: {}", patrol_tracker.get_map().iter().filter(|c| **c == 'X'.as_ascii().unwrap()).count());
-
-    let loop_tracker = PatrolTracker::new(map);
-    let (start_x, start_y) = loop_tracker.get_patrol_position();
-    let possible_obstacle_positions: Vec<_> = patrol_tracker.get_map()
-        .indexed_iter()
-        .filter(|(_, c)| **c == 'X'.as_ascii().unwrap())
-        .filter(|((x, y), _)| start_x != *x || start_y != *y)
-        .map(|(i, _)| i)
-        .collect();
-    let mut second_result = 0;
-    for possible_obstacle_position in possible_obstacle_positions {
-        if loop_tracker.would_obstacle_create_looping_patrol_path(possible_obstacle_position) {
-            second_result += 1;
+impl Solution for Day6 {
+    type Input = Array2<Char>;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {
+        let map = parse_char_matrix_from_str(input)?;
+        let guards: Vec<(usize, usize, char, &str)> = input
+            .lines()
+            .enumerate()
+            .flat_map(|(line_index, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| matches!(c, '^' | '>' | 'v' | '<'))
+                    .map(move |(column_index, c)| (line_index + 1, column_index + 1, c, line))
+            })
+            .collect();
+        match guards[..] {
+            [_] => Ok(map),
+            [] => Err(ParseError::new(1, 1, "", "Expected a guard ('^', '>', 'v' or '<'), found none").into()),
+            [_, (line, column, guard, text), ..] => Err(ParseError::new(
+                line,
+                column,
+                guard,
+                format!("Expected one guard, found {}", guards.len()),
+            )
+            .with_source_line(text)
+            .into()),
         }
     }
-    println!("The solution to part 2 is: {second_result}");
-    Ok(())
-}
\ No newline at end of file
+
+    fn part1(&self, map: &Self::Input) -> DynResult<Answer> {
+        let patrol_tracker = walk_patrol_path(map);
+        Ok(patrol_tracker.get_map().iter().filter(|c| **c == 'X'.as_ascii().unwrap()).count().into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> DynResult<Answer> {
+        let patrol_tracker = walk_patrol_path(map);
+        let loop_tracker = PatrolTracker::new(map.clone());
+        let (start_x, start_y) = loop_tracker.get_patrol_position();
+        let possible_obstacle_positions: Vec<_> = patrol_tracker.get_map()
+            .indexed_iter()
+            .filter(|(_, c)| **c == 'X'.as_ascii().unwrap())
+            .filter(|((x, y), _)| start_x != *x || start_y != *y)
+            .map(|(i, _)| i)
+            .collect();
+        let mut second_result = 0;
+        for possible_obstacle_position in possible_obstacle_positions {
+            if loop_tracker.would_obstacle_create_looping_patrol_path(possible_obstacle_position) {
+                debug!("An obstacle at {possible_obstacle_position:?} creates a loop");
+                second_result += 1;
+            }
+        }
+        Ok(second_result.into())
+    }
+}
+
+fn walk_patrol_path(map: &Array2<Char>) -> PatrolTracker {
+    let mut patrol_tracker = PatrolTracker::new(map.clone());
+    while patrol_tracker.take_step(true) == StepResult::KeepGoing {}
+    patrol_tracker
+}
@@ -2,6 +2,7 @@ use std::ascii::Char;
 use std::collections::{HashMap, HashSet};
 use once_cell::sync::Lazy;
 use ndarray::prelude::*;
+use crate::trace;
 
 static RIGHT_TURN_MATRIX: Lazy<Array2<isize>> = Lazy::new(|| {
     arr2(&[[0, 1],
@@ -63,7 +64,7 @@ impl PatrolTracker {
         self.up_positions.entry(pos[0] as usize).or_default().insert(pos[1] as usize);
     }
 
-    fn get_ray(&self) -> ArrayView<Char, Ix1> {
+    fn get_ray(&self) -> ArrayView<'_, Char, Ix1> {
         match (self.patrol_direction[0], self.patrol_direction[1]) {
             (0, 1) => {
                 self.map.slice(s![self.patrol_position[0], self.patrol_position[1]+1..])
@@ -81,7 +82,7 @@ impl PatrolTracker {
         }
     }
 
-    fn get_ray_mut(&mut self) -> ArrayViewMut<Char, Ix1> {
+    fn get_ray_mut(&mut self) -> ArrayViewMut<'_, Char, Ix1> {
         match (self.patrol_direction[0], self.patrol_direction[1]) {
             (0, 1) => {
                 self.map.slice_mut(s![self.patrol_position[0], self.patrol_position[1]..])
@@ -100,6 +101,12 @@ impl PatrolTracker {
     }
 
     pub fn take_step(&mut self, mark_path: bool) -> StepResult {
+        let result = self.step(mark_path);
+        trace!("Step ended at {} facing {} with {result:?}", self.patrol_position, self.patrol_direction);
+        result
+    }
+
+    fn step(&mut self, mark_path: bool) -> StepResult {
         let step_size = self.get_ray().iter().position(|c| *c == '#'.as_ascii().unwrap());
         match step_size {
             None => {
@@ -142,7 +149,7 @@ impl PatrolTracker {
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum StepResult {
     KeepGoing,
     OutOfBounds,
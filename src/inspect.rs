@@ -0,0 +1,356 @@
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::matrix::Matrix;
+use advent_of_code_2024::matrix::base::MatrixBase;
+use advent_of_code_2024::parsing::grid::parse_grid;
+use advent_of_code_2024::parsing::{
+    NumberFormat, Section, number_spans, read_input, split_sections,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::convert::Infallible;
+use std::hash::Hash;
+use std::path::Path;
+
+/// Number of entries shown of the character and separator histograms.
+const HISTOGRAM_LENGTH: usize = 10;
+
+/// Describes the structure of the input at `path` and suggests which parser fits it.
+pub fn inspect(path: &Path) -> DynResult<()> {
+    let text = read_input(path)?;
+    let blank_lines = text.lines().filter(|line| line.trim().is_empty()).count();
+    println!("Inspecting {}", path.display());
+    println!("Lines:      {} ({blank_lines} blank)", text.lines().count());
+
+    let sections = split_sections(&text);
+    println!("Sections:   {}", sections.len());
+    match sections.as_slice() {
+        [] => println!("Suggestion: the input is empty"),
+        [section] => describe(section, ""),
+        sections => {
+            for (index, section) in sections.iter().enumerate() {
+                println!(
+                    "Section {} (lines {} to {}):",
+                    index + 1,
+                    section.first_line,
+                    section.first_line + section.text.lines().count() - 1
+                );
+                describe(section, "  ");
+            }
+            println!(
+                "Suggestion: split_sections_exact::<{}> and parse every section on its own",
+                sections.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn describe(section: &Section, indent: &str) {
+    let lines: Vec<&str> = section.text.lines().collect();
+
+    let format = number_format(&lines);
+    let tokens: Vec<Vec<Token>> = lines.iter().map(|line| tokenize(line, format)).collect();
+    let number_counts: BTreeSet<usize> = tokens.iter().map(|line| numbers(line).count()).collect();
+
+    if let Some(grid) = detect_grid(section.text, &tokens) {
+        println!(
+            "{indent}Grid:       {} rows x {} columns",
+            grid.row_count(),
+            grid.column_count()
+        );
+        let characters = histogram(section.text.chars().filter(|c| *c != '\n'));
+        println!("{indent}Characters: {}", format_histogram(&characters));
+        println!("{indent}Suggestion: parse_grid, or read_file_to_char_matrix for an Array2<Char>");
+        return;
+    }
+    let lengths: BTreeSet<usize> = lines.iter().map(|line| line.chars().count()).collect();
+    println!(
+        "{indent}Grid:       no (line lengths from {} to {})",
+        lengths.first().unwrap(),
+        lengths.last().unwrap()
+    );
+
+    let values: Vec<i128> = tokens
+        .iter()
+        .flat_map(|line| numbers(line))
+        .filter_map(|number| number.parse().ok())
+        .collect();
+    match (values.iter().min(), values.iter().max()) {
+        (Some(min), Some(max)) => println!(
+            "{indent}Numbers:    {} to {} per line, from {min} to {max} (NumberFormat::{format:?})",
+            number_counts.first().unwrap(),
+            number_counts.last().unwrap()
+        ),
+        _ => println!("{indent}Numbers:    none"),
+    }
+
+    let separators = histogram(tokens.iter().flat_map(|line| {
+        line.iter().filter_map(|token| match token {
+            Token::Separator(separator) => Some(*separator),
+            _ => None,
+        })
+    }));
+    if !separators.is_empty() {
+        println!("{indent}Separators: {}", format_histogram(&separators));
+    }
+
+    println!(
+        "{indent}Suggestion: {}",
+        suggest(&tokens, !values.is_empty(), format)
+    );
+}
+
+/// Parses `text` as a grid if it looks like one: several lines of the same length without
+/// whitespace. Lines like `47|53` have the same length as well, but are lists of numbers.
+fn detect_grid(text: &str, tokens: &[Vec<Token>]) -> Option<MatrixBase<char>> {
+    let grid: MatrixBase<char> = parse_grid(text, Ok::<_, Infallible>).ok()?;
+    let is_grid = grid.row_count() > 1
+        && !text.lines().any(|line| line.contains(char::is_whitespace))
+        && tokens.iter().all(|line| numbers(line).count() < 2);
+    is_grid.then_some(grid)
+}
+
+/// Suggests how to parse lines with the given `tokens`. A line template is only suggested for free
+/// text if several lines share it, since a single line always fits its own template.
+fn suggest(tokens: &[Vec<Token>], has_numbers: bool, format: NumberFormat) -> String {
+    let number_templates: BTreeSet<String> =
+        tokens.iter().map(|line| template(line, false)).collect();
+    let templates: BTreeSet<String> = tokens.iter().map(|line| template(line, true)).collect();
+    let has_words = tokens
+        .iter()
+        .flatten()
+        .any(|token| matches!(token, Token::Word(_)));
+    let has_only_whitespace_separators = tokens.iter().flatten().all(|token| match token {
+        Token::Separator(separator) => separator.trim().is_empty(),
+        _ => true,
+    });
+    let uniform_template = [number_templates, templates]
+        .into_iter()
+        .find(|templates| templates.len() == 1)
+        .and_then(|templates| templates.into_iter().next());
+    match uniform_template {
+        _ if has_numbers && !has_words && has_only_whitespace_separators => match format {
+            NumberFormat::Signed => {
+                "parse_numbers with NumberFormat::Signed (or parse_integers for i32)".to_string()
+            }
+            NumberFormat::Unsigned => "parse_numbers with NumberFormat::Unsigned".to_string(),
+        },
+        Some(template) if template.contains("{}") && (tokens.len() > 1 || !has_words) => {
+            format!("LineTemplate::new({template:?})")
+        }
+        _ if has_words => {
+            "combinators, or find_matches to pick the interesting parts out of free-form text"
+                .to_string()
+        }
+        _ if has_numbers => format!("parse_numbers with NumberFormat::{format:?}"),
+        _ => "lines()".to_string(),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Number(&'a str),
+    Word(&'a str),
+    Separator(&'a str),
+}
+
+/// Reads signs as part of the numbers, unless a sign follows a letter or digit somewhere, as in
+/// the range `3-5` or the name `x-5`.
+fn number_format(lines: &[&str]) -> NumberFormat {
+    let has_sign_after_alphanumeric = lines.iter().any(|line| {
+        number_spans(line, NumberFormat::Signed).any(|span| {
+            line[span.start..].starts_with(['+', '-'])
+                && line[..span.start]
+                    .chars()
+                    .next_back()
+                    .is_some_and(char::is_alphanumeric)
+        })
+    });
+    if has_sign_after_alphanumeric {
+        NumberFormat::Unsigned
+    } else {
+        NumberFormat::Signed
+    }
+}
+
+/// Splits `line` into its numbers, read like `parse_numbers` does with `format`, and the words and
+/// separators between them.
+fn tokenize(line: &str, format: NumberFormat) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut end = 0;
+    for span in number_spans(line, format) {
+        split_words(&line[end..span.start], &mut tokens);
+        tokens.push(Token::Number(&line[span.clone()]));
+        end = span.end;
+    }
+    split_words(&line[end..], &mut tokens);
+    tokens
+}
+
+/// Splits text without numbers into words and the separators between them.
+fn split_words<'a>(mut text: &'a str, tokens: &mut Vec<Token<'a>>) {
+    while let Some(c) = text.chars().next() {
+        let is_word = c.is_alphabetic();
+        let length = prefix_length(text, |c| c.is_alphabetic() == is_word);
+        let token = if is_word {
+            Token::Word(&text[..length])
+        } else {
+            Token::Separator(&text[..length])
+        };
+        tokens.push(token);
+        text = &text[length..];
+    }
+}
+
+fn prefix_length<F: Fn(char) -> bool>(text: &str, predicate: F) -> usize {
+    text.find(|c| !predicate(c)).unwrap_or(text.len())
+}
+
+fn numbers<'a>(tokens: &'a [Token<'a>]) -> impl Iterator<Item = &'a str> {
+    tokens.iter().filter_map(|token| match token {
+        Token::Number(number) => Some(*number),
+        _ => None,
+    })
+}
+
+/// Shape of a line for [`LineTemplate`], with every number, and every word if `words_are_fields`,
+/// replaced by `{}`.
+///
+/// [`LineTemplate`]: advent_of_code_2024::parsing::template::LineTemplate
+fn template(tokens: &[Token], words_are_fields: bool) -> String {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Number(_) => "{}",
+            Token::Word(_) if words_are_fields => "{}",
+            Token::Word(text) | Token::Separator(text) => text,
+        })
+        .collect()
+}
+
+fn histogram<T: Eq + Hash + Ord, I: IntoIterator<Item = T>>(items: I) -> Vec<(T, usize)> {
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_default() += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    counts
+}
+
+fn format_histogram<T: std::fmt::Debug>(histogram: &[(T, usize)]) -> String {
+    let mut entries: Vec<String> = histogram
+        .iter()
+        .take(HISTOGRAM_LENGTH)
+        .map(|(item, count)| format!("{item:?} {count}"))
+        .collect();
+    if histogram.len() > HISTOGRAM_LENGTH {
+        entries.push(format!("and {} more", histogram.len() - HISTOGRAM_LENGTH));
+    }
+    entries.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokenize_lines(input: &str) -> Vec<Vec<Token<'_>>> {
+        let lines: Vec<&str> = input.lines().collect();
+        let format = number_format(&lines);
+        lines.iter().map(|line| tokenize(line, format)).collect()
+    }
+
+    fn suggest_for(input: &str) -> String {
+        let lines: Vec<&str> = input.lines().collect();
+        let tokens = tokenize_lines(input);
+        let has_numbers = tokens.iter().any(|line| numbers(line).next().is_some());
+        suggest(&tokens, has_numbers, number_format(&lines))
+    }
+
+    #[test]
+    fn detects_grids() {
+        let input = "..#.\n#...\n";
+        let grid = detect_grid(input, &tokenize_lines(input)).unwrap();
+        assert_eq!((grid.row_count(), grid.column_count()), (2, 4));
+        let input = "47|53\n97|13\n";
+        assert!(detect_grid(input, &tokenize_lines(input)).is_none());
+        let input = "ab cd\nef gh\n";
+        assert!(detect_grid(input, &tokenize_lines(input)).is_none());
+    }
+
+    #[test]
+    fn tokenizes_like_parse_numbers() {
+        assert_eq!(
+            tokenize("47|-53 x", NumberFormat::Signed),
+            [
+                Token::Number("47"),
+                Token::Separator("|"),
+                Token::Number("-53"),
+                Token::Separator(" "),
+                Token::Word("x"),
+            ]
+        );
+        assert_eq!(
+            tokenize("3-5", NumberFormat::Unsigned),
+            [
+                Token::Number("3"),
+                Token::Separator("-"),
+                Token::Number("5")
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizes_non_ascii_numerals_as_separators() {
+        assert_eq!(
+            tokenize("١٢٣ ٤", NumberFormat::Signed),
+            [Token::Separator("١٢٣ ٤")]
+        );
+        assert_eq!(
+            tokenize("1 ½ 2", NumberFormat::Signed),
+            [
+                Token::Number("1"),
+                Token::Separator(" ½ "),
+                Token::Number("2")
+            ]
+        );
+        assert_eq!(suggest_for("١٢٣ ٤\n"), "lines()");
+    }
+
+    #[test]
+    fn reads_signs_after_letters_or_digits_as_separators() {
+        assert_eq!(number_format(&["3 -5", "+1 2"]), NumberFormat::Signed);
+        assert_eq!(number_format(&["3,-5"]), NumberFormat::Signed);
+        assert_eq!(number_format(&["3-5 7-9"]), NumberFormat::Unsigned);
+        assert_eq!(number_format(&["x-5"]), NumberFormat::Unsigned);
+    }
+
+    #[test]
+    fn suggests_parsers() {
+        assert_eq!(
+            suggest_for("3   4\n4   3\n"),
+            "parse_numbers with NumberFormat::Signed (or parse_integers for i32)"
+        );
+        assert_eq!(
+            suggest_for("47|53\n97|13\n"),
+            r#"LineTemplate::new("{}|{}")"#
+        );
+        assert_eq!(
+            suggest_for("p=0,4 v=3,-3\np=6,3 v=-1,-3\n"),
+            r#"LineTemplate::new("p={},{} v={},{}")"#
+        );
+        assert_eq!(
+            suggest_for("3-5 7-9\n"),
+            r#"LineTemplate::new("{}-{} {}-{}")"#
+        );
+    }
+
+    #[test]
+    fn suggests_find_matches_for_a_single_free_form_line() {
+        assert_eq!(
+            suggest_for(
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n"
+            ),
+            "combinators, or find_matches to pick the interesting parts out of free-form text"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+#![feature(ascii_char)]
+#![allow(dead_code)]
+
+extern crate core;
+extern crate regex;
+extern crate once_cell;
+extern crate ndarray;
+
+use std::error::Error;
+
+pub use day4::matrix;
+
+pub mod alloc;
+pub mod answers;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod log;
+pub mod parsing;
+pub mod provider;
+pub mod report;
+pub mod solution;
+pub mod submission;
+
+pub type DynResult<T> = Result<T, Box<dyn Error>>;
@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+thread_local! {
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Level {
+    type Err = InvalidLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| InvalidLevelError(s.to_string()))
+    }
+}
+
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn max_level() -> Level {
+    Level::ALL[MAX_LEVEL.load(Ordering::Relaxed) as usize - 1]
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes a message to standard error, prefixed with its level and the spans of this thread.
+///
+/// Use the [`error!`](crate::error), [`warn!`](crate::warn), [`info!`](crate::info),
+/// [`debug!`](crate::debug) and [`trace!`](crate::trace) macros, which skip formatting the message
+/// when its level is disabled.
+pub fn log(level: Level, message: Arguments) {
+    SPANS.with_borrow(|spans| {
+        if spans.is_empty() {
+            eprintln!("[{level}] {message}");
+        } else {
+            eprintln!("[{level} {}] {message}", spans.join(" "));
+        }
+    });
+}
+
+/// Marks the messages of this thread with `name` until the returned guard is dropped.
+pub fn span<S: Into<String>>(name: S) -> SpanGuard {
+    SPANS.with_borrow_mut(|spans| spans.push(name.into()));
+    SpanGuard(())
+}
+
+pub struct SpanGuard(());
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        SPANS.with_borrow_mut(|spans| spans.pop());
+    }
+}
+
+#[macro_export]
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::log($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[derive(Debug)]
+pub struct InvalidLevelError(String);
+
+impl Display for InvalidLevelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid log level '{}', expected error, warn, info, debug or trace",
+            self.0
+        )
+    }
+}
+
+impl Error for InvalidLevelError {}
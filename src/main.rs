@@ -1,65 +1,171 @@
-#![feature(ascii_char, trait_alias)]
-#![allow(dead_code)]
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::log::set_max_level;
+use advent_of_code_2024::parsing::normalize::set_tab_width;
+use advent_of_code_2024::provider::InputCache;
+use advent_of_code_2024::report::{
+    PartResult, run_day, run_day_streaming, run_days_parallel, to_json,
+};
+use advent_of_code_2024::solution::{Solver, get_solution, get_streaming_solution, solved_days};
+use bench::bench;
+use memory::memory;
+use cli::{Cli, CliError, Command, USAGE};
+use inspect::inspect;
+use scaffold::new_day;
+use submit::submit;
+use verify::verify;
+use watch::watch;
+use std::process::ExitCode;
 
-extern crate core;
-extern crate regex;
-extern crate once_cell;
-extern crate ndarray;
+mod bench;
+mod cli;
+mod inspect;
+mod memory;
+mod scaffold;
+mod submit;
+mod verify;
+mod watch;
 
-use day2::solve_day2;
-use day3::solve_day3;
-use day4::solve_day4;
-use day5::solve_day5;
-use day6::solve_day6;
-use parsing::parse_integers;
-use std::error::Error;
-use std::path::Path;
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code_2024::alloc::CountingAllocator =
+    advent_of_code_2024::alloc::CountingAllocator;
 
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod parsing;
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
 
-pub type DynResult<T> = Result<T, Box<dyn Error>>;
+fn try_main() -> DynResult<()> {
+    let mut cli = Cli::parse(std::env::args().skip(1))?;
+    if cli.help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+    set_max_level(cli.log_level);
+    set_tab_width(cli.tab_width);
+
+    if cli.command == Command::Inspect {
+        return match &cli.file {
+            Some(file) => inspect(file),
+            None => Err(CliError("`inspect` expects a file".to_string()).into()),
+        };
+    }
+    if cli.command == Command::NewDay {
+        return match cli.days.as_deref() {
+            Some([day]) => new_day(*day),
+            _ => Err(CliError("`new-day` expects exactly one day".to_string()).into()),
+        };
+    }
+
+    let days = cli.days.clone().unwrap_or_else(|| solved_days().collect());
+    let mut solvers = vec![];
+    for day in days {
+        let solver = get_solution(day)
+            .ok_or_else(|| CliError(format!("Day {day} has not been solved yet")))?;
+        solvers.push((day, solver));
+    }
+    if !cli.example && cli.input.is_none() {
+        resolve_missing_inputs(&mut cli, &solvers)?;
+    }
+
+    match cli.command {
+        Command::Run => run(&cli, &solvers),
+        Command::Verify => verify(&cli, &solvers),
+        Command::Bench => bench(&cli, &solvers),
+        Command::Memory => memory(&cli, &solvers),
+        Command::Watch => watch(&cli, &solvers),
+        Command::Submit => match solvers.as_slice() {
+            [(day, solver)] => submit(&cli, *day, *solver),
+            _ => Err(CliError("`submit` expects exactly one day".to_string()).into()),
+        },
+        Command::Inspect | Command::NewDay => unreachable!(),
+    }
+}
 
-fn main() -> DynResult<()> {
-    solve_day1("input/day1/input.txt")?;
-    solve_day2("input/day2/input.txt")?;
-    solve_day3("input/day3/input.txt")?;
-    solve_day4("input/day4/input.txt")?;
-    solve_day5("input/day5/input.txt")?;
-    solve_day6("input/day6/input.txt")?;
+/// Points every day without a local input to its cached input, downloading it if necessary.
+fn resolve_missing_inputs(cli: &mut Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    let cache = InputCache::from_env();
+    for (day, _) in solvers.iter() {
+        if !cli.inputs.contains_key(day) {
+            let path = cache.resolve(*day, &cli.input_path(*day))?;
+            cli.inputs.insert(*day, path);
+        }
+    }
     Ok(())
 }
 
-fn solve_day1<P: AsRef<Path>>(path: P) -> DynResult<()> {
-    println!(
-        "Solving Day 1 Part 1 on the input {}",
-        path.as_ref().to_str().unwrap()
-    );
-    let input = parse_integers(path)?;
-    let mut left_list: Vec<i32> = input.iter().map(|ints| ints[0]).collect();
-    left_list.sort();
-    let mut right_list: Vec<i32> = input.iter().map(|ints| ints[1]).collect();
-    right_list.sort();
-    let mut first_result = 0;
-    for (left, right) in left_list.iter().zip(right_list.iter()) {
-        if left < right {
-            first_result += right - left;
-        } else {
-            first_result += left - right;
+/// Solves one day, streaming its input if `--stream` was given.
+fn solve_day(cli: &Cli, day: u8, solver: &dyn Solver) -> DynResult<Vec<PartResult>> {
+    let path = cli.input_path(day);
+    if !cli.stream {
+        return Ok(run_day(day, solver, &path, cli.parts.iter()));
+    }
+    let streaming_solver = get_streaming_solution(day)
+        .ok_or_else(|| CliError(format!("Day {day} cannot stream its input")))?;
+    Ok(run_day_streaming(day, streaming_solver, &path, cli.parts.iter()))
+}
+
+fn run(cli: &Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    if cli.stream && cli.jobs.is_some() {
+        return Err(CliError("`--stream` cannot be combined with `--jobs`".to_string()).into());
+    }
+    if cli.jobs.is_none() && !cli.json {
+        for (day, solver) in solvers.iter() {
+            for result in solve_day(cli, *day, *solver)? {
+                println!(
+                    "Day {} Part {}: {}",
+                    result.day,
+                    result.part,
+                    result.answer?
+                );
+            }
         }
+        return Ok(());
     }
-    println!("The solution is: {}", first_result);
 
-    println!("Solving Day 1 Part 2 on the same input");
-    let mut second_result = 0;
-    for left_entry in left_list.iter() {
-        let right_occurrences = right_list.iter().filter(|r| *r == left_entry).count();
-        second_result += left_entry * right_occurrences as i32;
+    let results = if let Some(jobs) = cli.jobs {
+        let days: Vec<_> = solvers
+            .iter()
+            .map(|(day, solver)| (*day, *solver, cli.input_path(*day)))
+            .collect();
+        let parts: Vec<_> = cli.parts.iter().collect();
+        run_days_parallel(&days, &parts, jobs)
+    } else {
+        let mut results = vec![];
+        for (day, solver) in solvers.iter() {
+            results.extend(solve_day(cli, *day, *solver)?);
+        }
+        results
+    };
+
+    if cli.json {
+        println!("{}", to_json(&results));
+    } else {
+        print_summary(&results);
+    }
+    let failures = results.iter().filter(|result| result.answer.is_err()).count();
+    if failures > 0 {
+        return Err(CliError(format!("{failures} part(s) failed")).into());
     }
-    println!("The solution is: {}", second_result);
     Ok(())
 }
+
+fn print_summary(results: &[PartResult]) {
+    for result in results {
+        let outcome = match &result.answer {
+            Ok(answer) => format!("{answer} ({:.3?})", result.elapsed),
+            Err(e) => format!("ERROR {e}"),
+        };
+        println!(
+            "Day {} Part {} on {}: {outcome}",
+            result.day,
+            result.part,
+            result.input.display()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::cli::{Cli, CliError};
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::alloc::{AllocationStats, measure_allocations};
+use advent_of_code_2024::log::span;
+use advent_of_code_2024::parsing::{locate_error, read_input};
+use advent_of_code_2024::solution::Solver;
+
+pub fn memory(cli: &Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    if !cfg!(feature = "alloc-stats") {
+        return Err(CliError(
+            "Allocation counting is disabled, rebuild with `--features alloc-stats`".to_string(),
+        )
+        .into());
+    }
+
+    for (day, solver) in solvers.iter() {
+        let _day_span = span(format!("day{day}"));
+        let path = cli.input_path(*day);
+        println!(
+            "Measuring memory of Day {day} on the input {}",
+            path.display()
+        );
+        println!(
+            "{:<8}{:>14}{:>14}{:>14}",
+            "phase", "allocations", "allocated", "peak"
+        );
+        let text = read_input(&path)?;
+        let (input, stats) = measure_allocations(|| solver.parse(&text));
+        let input = input.map_err(|e| locate_error(e, &path))?;
+        print_stats("parse", &stats);
+        for part in cli.parts.iter() {
+            let (answer, stats) = measure_allocations(|| solver.solve(part, &input));
+            answer?;
+            print_stats(&format!("part {part}"), &stats);
+        }
+    }
+    Ok(())
+}
+
+fn print_stats(phase: &str, stats: &AllocationStats) {
+    println!(
+        "{:<8}{:>14}{:>14}{:>14}",
+        phase,
+        stats.allocations,
+        format_bytes(stats.bytes_allocated),
+        format_bytes(stats.peak_bytes)
+    );
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,268 @@
+use super::{ParseError, column_of};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Result of running a parser, which is any `Fn(&str) -> Step<T>`: the parsed value and the rest
+/// of the input, or where it failed.
+///
+/// Parsers are built from the combinators of this module, e.g. for a Day 3 instruction:
+///
+/// ```
+/// use advent_of_code_2024::parsing::combinators::*;
+///
+/// let mul = delimited(tag("mul("), separated_list(number::<i32>, tag(",")), tag(")"));
+/// assert_eq!(parse_complete(mul, "mul(3,-4)"), Ok(vec![3, -4]));
+/// ```
+pub type Step<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// Input that was left when the parser failed, which locates the failure in the whole input.
+    pub rest: &'a str,
+    pub message: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new<M: Into<String>>(rest: &'a str, message: M) -> Self {
+        Self {
+            rest,
+            message: message.into(),
+        }
+    }
+}
+
+/// Runs `parser` on `input`, failing unless it consumes all of it. A failure is reported as a
+/// [`ParseError`] at its line and column in `input`.
+pub fn parse_complete<'a, T, P>(parser: P, input: &'a str) -> Result<T, ParseError>
+where
+    P: Fn(&'a str) -> Step<'a, T>,
+{
+    let failure = match parser(input) {
+        Ok((value, "")) => return Ok(value),
+        Ok((_, rest)) => Failure::new(rest, "Expected the end of the input"),
+        Err(failure) => failure,
+    };
+    let offset = input.len() - failure.rest.len();
+    let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = input[line_start..].lines().next().unwrap_or("");
+    Err(ParseError::new(
+        input[..offset].matches('\n').count() + 1,
+        column_of(line, offset - line_start),
+        failure.rest.lines().next().unwrap_or(""),
+        failure.message,
+    )
+    .with_source_line(line))
+}
+
+/// Matches the literal `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(&'a str) -> Step<'a, &'a str> {
+    move |input| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => Err(Failure::new(input, format!("Expected '{expected}'"))),
+    }
+}
+
+/// Parses an integer with an optional sign, e.g. `number::<i64>`.
+pub fn number<'a, T>(input: &'a str) -> Step<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let sign_length = usize::from(input.starts_with(['+', '-']));
+    let digit_count = input[sign_length..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digit_count == 0 {
+        return Err(Failure::new(input, "Expected a digit"));
+    }
+    let (text, rest) = input.split_at(sign_length + digit_count);
+    match text.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(e) => Err(Failure::new(input, format!("Invalid number: {e}"))),
+    }
+}
+
+/// Parses one or more `item`s separated by `separator`. Every separator must be followed by an item.
+pub fn separated_list<'a, T, S, P, Q>(item: P, separator: Q) -> impl Fn(&'a str) -> Step<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> Step<'a, T>,
+    Q: Fn(&'a str) -> Step<'a, S>,
+{
+    move |input| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after_separator)) = separator(rest) {
+            let (next, after_item) = item(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Tries each parser of a tuple in turn, e.g. `alt((tag("do()"), tag("don't()")))`.
+pub fn alt<'a, T, A>(alternatives: A) -> impl Fn(&'a str) -> Step<'a, T>
+where
+    A: Alternatives<'a, T>,
+{
+    move |input| alternatives.parse_first(input)
+}
+
+/// Tuple of parsers with the same output, see [`alt`].
+pub trait Alternatives<'a, T> {
+    /// Returns the result of the first parser that succeeds, or the failure that got furthest.
+    fn parse_first(&self, input: &'a str) -> Step<'a, T>;
+}
+
+macro_rules! impl_alternatives_for_tuple {
+    ($($p:ident $index:tt),*) => {
+        impl<'a, T, $($p),*> Alternatives<'a, T> for ($($p,)*)
+        where
+            $($p: Fn(&'a str) -> Step<'a, T>),*
+        {
+            fn parse_first(&self, input: &'a str) -> Step<'a, T> {
+                let mut furthest: Option<Failure<'a>> = None;
+                $(
+                    match (self.$index)(input) {
+                        Ok(result) => return Ok(result),
+                        Err(failure) => {
+                            if furthest
+                                .as_ref()
+                                .is_none_or(|f| failure.rest.len() < f.rest.len())
+                            {
+                                furthest = Some(failure);
+                            }
+                        }
+                    }
+                )*
+                Err(furthest.unwrap())
+            }
+        }
+    };
+}
+
+impl_alternatives_for_tuple!(A 0, B 1);
+impl_alternatives_for_tuple!(A 0, B 1, C 2);
+impl_alternatives_for_tuple!(A 0, B 1, C 2, D 3);
+impl_alternatives_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_alternatives_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Applies `parser` as often as it succeeds, possibly never.
+pub fn many<'a, T, P>(parser: P) -> impl Fn(&'a str) -> Step<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> Step<'a, T>,
+{
+    move |mut input| {
+        let mut items = vec![];
+        while let Ok((item, rest)) = parser(input) {
+            if rest.len() == input.len() {
+                break;
+            }
+            items.push(item);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// Parses `open`, `parser` and `close` in sequence and keeps the output of `parser`.
+pub fn delimited<'a, T, O, C, P, Q, R>(
+    open: P,
+    parser: Q,
+    close: R,
+) -> impl Fn(&'a str) -> Step<'a, T>
+where
+    P: Fn(&'a str) -> Step<'a, O>,
+    Q: Fn(&'a str) -> Step<'a, T>,
+    R: Fn(&'a str) -> Step<'a, C>,
+{
+    move |input| {
+        let (_, rest) = open(input)?;
+        let (value, rest) = parser(rest)?;
+        let (_, rest) = close(rest)?;
+        Ok((value, rest))
+    }
+}
+
+/// Transforms the output of `parser` with `f`.
+pub fn map<'a, T, U, P, F>(parser: P, f: F) -> impl Fn(&'a str) -> Step<'a, U>
+where
+    P: Fn(&'a str) -> Step<'a, T>,
+    F: Fn(T) -> U,
+{
+    move |input| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mul<'a>(input: &'a str) -> Step<'a, Vec<i32>> {
+        delimited(
+            tag("mul("),
+            separated_list(number::<i32>, tag(",")),
+            tag(")"),
+        )(input)
+    }
+
+    #[test]
+    fn parses_complete_input() {
+        assert_eq!(parse_complete(mul, "mul(3,-4)"), Ok(vec![3, -4]));
+        assert_eq!(parse_complete(number::<u64>, "007"), Ok(7));
+    }
+
+    #[test]
+    fn locates_failure() {
+        let error =
+            parse_complete(separated_list(mul, tag("\n")), "mul(1,2)\nmul(3,x)").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.message, "Expected a digit");
+        assert_eq!(error.text, "x)");
+        assert_eq!(error.source_line.as_deref(), Some("mul(3,x)"));
+    }
+
+    #[test]
+    fn requires_the_whole_input() {
+        let error = parse_complete(mul, "mul(1)mul(2)").unwrap_err();
+        assert_eq!(error.column, 7);
+        assert_eq!(error.message, "Expected the end of the input");
+    }
+
+    #[test]
+    fn alt_takes_the_first_success() {
+        let instruction = alt((tag("do()"), tag("don't()")));
+        assert_eq!(instruction("don't()x"), Ok(("don't()", "x")));
+    }
+
+    #[test]
+    fn alt_reports_the_furthest_failure() {
+        let instruction = alt((map(tag("do()"), |_| vec![]), mul));
+        let failure = instruction("mul(1,x)").unwrap_err();
+        assert_eq!(failure.rest, "x)");
+        assert_eq!(failure.message, "Expected a digit");
+    }
+
+    #[test]
+    fn many_stops_without_progress() {
+        assert_eq!(many(tag("a"))("aab"), Ok((vec!["a", "a"], "b")));
+        assert_eq!(many(tag("a"))("b"), Ok((vec![], "b")));
+        assert_eq!(many(many(tag("a")))("aab"), Ok((vec![vec!["a", "a"]], "b")));
+    }
+
+    #[test]
+    fn separated_list_rejects_trailing_separator() {
+        let list = separated_list(number::<i32>, tag(","));
+        assert_eq!(list("1,2;"), Ok((vec![1, 2], ";")));
+        let failure = list("1,2,;").unwrap_err();
+        assert_eq!(failure.rest, ";");
+        assert_eq!(failure.message, "Expected a digit");
+    }
+
+    #[test]
+    fn number_reports_overflow() {
+        let failure = number::<u8>("256").unwrap_err();
+        assert_eq!(failure.rest, "256");
+        assert!(failure.message.starts_with("Invalid number"));
+    }
+}
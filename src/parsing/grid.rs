@@ -0,0 +1,192 @@
+use super::{ParseError, locate_error, read_file_to_string};
+use crate::DynResult;
+use crate::matrix::MutMatrix;
+use crate::matrix::base::MatrixBase;
+use crate::matrix::bool::BoolMatrix;
+use crate::matrix::sparse::SparseMatrix;
+use ndarray::Array2;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::path::Path;
+
+/// Matrix that can be built from the cells of a grid, listed row by row.
+pub trait FromCells<T>: Sized {
+    fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self;
+}
+
+impl<T> FromCells<T> for MatrixBase<T> {
+    fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        MatrixBase::new(rows, columns, cells)
+    }
+}
+
+impl FromCells<bool> for BoolMatrix {
+    fn from_cells(rows: usize, columns: usize, cells: Vec<bool>) -> Self {
+        let mut matrix = BoolMatrix::new(rows, columns);
+        for (index, value) in cells.into_iter().enumerate() {
+            matrix.set(index % columns, index / columns, value);
+        }
+        matrix
+    }
+}
+
+/// Cells equal to `T::default()` are the zero of the sparse matrix and are not stored.
+impl<T: Clone + Eq + Hash + Default> FromCells<T> for SparseMatrix<T> {
+    fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        SparseMatrix::from_matrix(MatrixBase::new(rows, columns, cells), T::default())
+    }
+}
+
+impl<T> FromCells<T> for Array2<T> {
+    fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        Array2::from_shape_vec((rows, columns), cells).expect("Grid has rows * columns cells")
+    }
+}
+
+/// Parses a rectangular grid with one row per line, mapping every char to a cell with `cell`.
+///
+/// An error returned by `cell` becomes the message of the [`ParseError`] at that char, e.g.
+/// `parse_grid::<MatrixBase<u8>, _, _, _>(input, |c| c.to_digit(10).map(|d| d as u8).ok_or("Expected a digit"))`.
+pub fn parse_grid<M, T, E, F>(input: &str, mut cell: F) -> Result<M, ParseError>
+where
+    M: FromCells<T>,
+    E: Display,
+    F: FnMut(char) -> Result<T, E>,
+{
+    let mut rows = 0;
+    let mut columns: Option<usize> = None;
+    let mut cells = vec![];
+    for (line_index, line) in input.lines().enumerate() {
+        rows += 1;
+        let mut cur_columns = 0;
+        for c in line.chars() {
+            let value = cell(c).map_err(|e| {
+                ParseError::new(line_index + 1, cur_columns + 1, c, e.to_string())
+                    .with_source_line(line)
+            })?;
+            cur_columns += 1;
+            cells.push(value);
+        }
+        match columns {
+            Some(cols) if cols != cur_columns => {
+                return Err(ParseError::new(
+                    line_index + 1,
+                    cols.min(cur_columns) + 1,
+                    line,
+                    format!(
+                        "Row {} has {cur_columns} columns, expected {cols}",
+                        line_index + 1
+                    ),
+                )
+                .with_source_line(line));
+            }
+            Some(_) => {}
+            None => columns = Some(cur_columns),
+        }
+    }
+    let columns = columns
+        .ok_or_else(|| ParseError::new(1, 1, "", "Expected a grid, found an empty input"))?;
+    Ok(M::from_cells(rows, columns, cells))
+}
+
+pub fn read_file_to_grid<M, T, E, F, P>(path: P, cell: F) -> DynResult<M>
+where
+    M: FromCells<T>,
+    E: Display,
+    F: FnMut(char) -> Result<T, E>,
+    P: AsRef<Path>,
+{
+    let input = read_file_to_string(&path)?;
+    parse_grid(&input, cell).map_err(|e| locate_error(e.into(), path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+    use std::convert::Infallible;
+
+    fn char_grid(input: &str) -> MatrixBase<char> {
+        parse_grid(input, Ok::<_, Infallible>).unwrap()
+    }
+
+    fn strings<I, W>(walks: I) -> Vec<String>
+    where
+        I: Iterator<Item = W>,
+        W: Iterator<Item = char>,
+    {
+        walks.map(|walk| walk.collect()).collect()
+    }
+
+    #[test]
+    fn wide_grid() {
+        let grid = char_grid("abc\ndef\n");
+        assert_eq!((grid.row_count(), grid.column_count()), (2, 3));
+        assert_eq!(grid.get(2, 0), Some('c'));
+        assert_eq!(grid.get(0, 1), Some('d'));
+        assert_eq!(grid.get(2, 1), Some('f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(strings(grid.rows()), ["abc", "def"]);
+        assert_eq!(strings(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(strings(grid.down_diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(strings(grid.up_diagonals()), ["a", "db", "ec", "f"]);
+    }
+
+    #[test]
+    fn tall_grid() {
+        let grid = char_grid("ab\ncd\nef\n");
+        assert_eq!((grid.row_count(), grid.column_count()), (3, 2));
+        assert_eq!(grid.get(1, 0), Some('b'));
+        assert_eq!(grid.get(0, 2), Some('e'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(strings(grid.rows()), ["ab", "cd", "ef"]);
+        assert_eq!(strings(grid.columns()), ["ace", "bdf"]);
+        assert_eq!(strings(grid.down_diagonals()), ["e", "cf", "ad", "b"]);
+        assert_eq!(strings(grid.up_diagonals()), ["a", "cb", "ed", "f"]);
+    }
+
+    #[test]
+    fn views_of_non_square_grids() {
+        let grid = char_grid("abc\ndef\n");
+        let view = grid.view(1, 3, 0, 2).unwrap();
+        assert_eq!(strings(view.rows()), ["bc", "ef"]);
+        assert!(grid.view(0, 2, 0, 3).is_none());
+        let grid = char_grid("ab\ncd\nef\n");
+        let view = grid.view(0, 2, 1, 3).unwrap();
+        assert_eq!(strings(view.rows()), ["cd", "ef"]);
+        assert!(grid.view(0, 3, 0, 2).is_none());
+    }
+
+    #[test]
+    fn bool_matrix_from_cells() {
+        let grid: BoolMatrix = parse_grid("#..\n..#\n", |c| Ok::<_, Infallible>(c == '#')).unwrap();
+        assert_eq!((grid.row_count(), grid.column_count()), (2, 3));
+        assert_eq!(grid.get(0, 0), Some(true));
+        assert_eq!(grid.get(2, 0), Some(false));
+        assert_eq!(grid.get(2, 1), Some(true));
+        assert_eq!(grid.get(3, 0), None);
+    }
+
+    #[test]
+    fn sparse_matrix_from_cells() {
+        let grid: SparseMatrix<u32> =
+            parse_grid("10\n03\n20\n", |c| c.to_digit(10).ok_or("Expected a digit")).unwrap();
+        assert_eq!((grid.row_count(), grid.column_count()), (3, 2));
+        assert_eq!(grid.get(0, 0), Some(1));
+        assert_eq!(grid.get(1, 1), Some(3));
+        assert_eq!(grid.get(0, 2), Some(2));
+        assert_eq!(grid.get(1, 2), Some(0));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        let error =
+            parse_grid::<MatrixBase<char>, _, _, _>("abc\nab\n", Ok::<_, Infallible>).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "Row 2 has 2 columns, expected 3");
+    }
+}
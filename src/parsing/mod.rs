@@ -0,0 +1,519 @@
+use std::ascii::Char;
+use crate::DynResult;
+use regex::Regex;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, stdin};
+use std::iter;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use ndarray::Array2;
+use crate::matrix::char_as_ascii;
+use self::grid::parse_grid;
+use self::normalize::{NormalizedLines, normalize};
+
+pub mod combinators;
+pub mod grid;
+pub mod normalize;
+pub mod template;
+
+/// Input path that stands for standard input.
+pub const STDIN_PATH: &str = "-";
+
+static STDIN_CONTENT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Reads the input at `path`, or standard input if `path` is [`STDIN_PATH`].
+///
+/// Standard input is only read once, so several days or benchmark iterations can share it, even
+/// when they run on different threads.
+pub fn read_input<P: AsRef<Path>>(path: P) -> DynResult<String> {
+    if path.as_ref() != Path::new(STDIN_PATH) {
+        return read_file_to_string(path);
+    }
+    let mut content = STDIN_CONTENT.lock().unwrap();
+    if content.is_none() {
+        *content = Some(read_to_string(stdin().lock())?);
+    }
+    Ok(content.clone().unwrap())
+}
+
+/// Reads everything from `reader` and normalizes it, see [`normalize`].
+pub fn read_to_string<R: Read>(mut reader: R) -> DynResult<String> {
+    let mut result = String::new();
+    reader.read_to_string(&mut result)?;
+    let (result, report) = normalize(&result);
+    if !report.is_unchanged() {
+        crate::debug!("Normalized the input: {report}");
+    }
+    Ok(result)
+}
+
+/// Opens the input at `path` to be read line by line, or standard input if `path` is
+/// [`STDIN_PATH`].
+pub fn open_input<P: AsRef<Path>>(path: P) -> DynResult<Box<dyn BufRead>> {
+    if path.as_ref() == Path::new(STDIN_PATH) {
+        return Ok(Box::new(stdin().lock()));
+    }
+    Ok(Box::new(BufReader::new(File::open(path)?)))
+}
+
+pub fn read_file_to_string<P: AsRef<Path>>(path: P) -> DynResult<String> {
+    read_to_string(File::open(path)?)
+}
+
+pub fn read_file_to_char_matrix<P: AsRef<Path>>(path: P) -> DynResult<Array2<Char>> {
+    let input = read_file_to_string(&path)?;
+    parse_char_matrix_from_str(&input).map_err(|e| locate_error(e, path))
+}
+
+pub fn parse_char_matrix_from_str(input: &str) -> DynResult<Array2<Char>> {
+    Ok(parse_grid(input, char_as_ascii)?)
+}
+
+pub fn parse_integers<P: AsRef<Path>>(path: P) -> DynResult<Vec<Vec<i32>>> {
+    parse_numbers(path, NumberFormat::Signed)
+}
+
+pub fn parse_integers_from_str(input: &str) -> DynResult<Vec<Vec<i32>>> {
+    parse_numbers_from_str(input, NumberFormat::Signed)
+}
+
+/// How to treat a `+` or `-` directly in front of a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// The sign belongs to the number, so `3-5` contains `3` and `-5`.
+    Signed,
+    /// Signs are separators like any other non-digit, so `3-5` contains `3` and `5`.
+    Unsigned,
+}
+
+pub fn parse_numbers<T>(path: impl AsRef<Path>, format: NumberFormat) -> DynResult<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let input = read_file_to_string(&path)?;
+    parse_numbers_from_str(&input, format).map_err(|e| locate_error(e, path))
+}
+
+/// Parses the numbers of every line, e.g. `parse_numbers_from_str::<u64>(input, Unsigned)`.
+///
+/// A number is a maximal run of ASCII digits, so `0` and `007` are numbers as well.
+pub fn parse_numbers_from_str<T>(input: &str, format: NumberFormat) -> DynResult<Vec<Vec<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut result = vec![];
+    for (line_index, line) in input.lines().enumerate() {
+        result.push(parse_numbers_in_line(line, line_index + 1, format)?);
+    }
+    Ok(result)
+}
+
+pub fn parse_numbers_in_line<T>(
+    line: &str,
+    line_number: usize,
+    format: NumberFormat,
+) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    number_spans(line, format)
+        .map(|span| {
+            let text = &line[span.clone()];
+            text.parse().map_err(|e| {
+                ParseError::new(
+                    line_number,
+                    column_of(line, span.start),
+                    text,
+                    format!("Invalid number: {e}"),
+                )
+                .with_source_line(line)
+            })
+        })
+        .collect()
+}
+
+/// Byte ranges of the numbers in `line`, including their sign if `format` is
+/// [`NumberFormat::Signed`].
+pub fn number_spans(line: &str, format: NumberFormat) -> impl Iterator<Item = Range<usize>> + '_ {
+    let bytes = line.as_bytes();
+    let mut index = 0;
+    iter::from_fn(move || {
+        while index < bytes.len() && !bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+        let mut start = index;
+        if format == NumberFormat::Signed && start > 0 && matches!(bytes[start - 1], b'+' | b'-') {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        Some(start..index)
+    })
+}
+
+/// Lazily parses the numbers of every line read from `reader`, so that only one line is held in
+/// memory at a time. Every line comes with its text, so that later errors can show it.
+pub fn stream_numbers<T, R>(
+    reader: R,
+    format: NumberFormat,
+) -> impl Iterator<Item = DynResult<(String, Vec<T>)>>
+where
+    T: FromStr,
+    T::Err: Display,
+    R: BufRead,
+{
+    NormalizedLines::new(reader).enumerate().map(move |(line_index, line)| {
+        let line = line?;
+        let numbers = parse_numbers_in_line(&line, line_index + 1, format)?;
+        Ok((line, numbers))
+    })
+}
+
+/// A block of consecutive non-blank lines of an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// 1-based line number of the first line of the section within the whole input.
+    pub first_line: usize,
+}
+
+impl Section<'_> {
+    /// Runs `parser` on the text of the section, shifting the lines of its [`ParseError`]s so
+    /// they point into the whole input.
+    pub fn parse<T, F>(&self, parser: F) -> DynResult<T>
+    where
+        F: FnOnce(&str) -> DynResult<T>,
+    {
+        parser(self.text).map_err(|mut e| {
+            if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+                parse_error.line += self.first_line - 1;
+            }
+            e
+        })
+    }
+}
+
+/// Splits `input` into the sections between blank lines. Several blank lines in a row separate
+/// just two sections.
+pub fn split_sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+    for (line_index, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                sections.push(Section {
+                    text: &input[start..end],
+                    first_line,
+                });
+            }
+        } else {
+            let end = offset + line.trim_end_matches(['\r', '\n']).len();
+            match &mut current {
+                Some((_, _, current_end)) => *current_end = end,
+                None => current = Some((line_index + 1, offset, end)),
+            }
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, start, end)) = current {
+        sections.push(Section {
+            text: &input[start..end],
+            first_line,
+        });
+    }
+    sections
+}
+
+/// Like [`split_sections`], but fails unless there are exactly `N` sections, e.g.
+/// `let [rules, updates] = split_sections_exact(input)?;`.
+pub fn split_sections_exact<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let sections = split_sections(input);
+    let line = match sections.get(N) {
+        Some(extra_section) => extra_section.first_line,
+        None => input.lines().count().max(1),
+    };
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        ParseError::new(
+            line,
+            1,
+            "",
+            format!("Expected {N} sections separated by blank lines, found {found}"),
+        )
+    })
+}
+
+pub fn process_input_for_matches<P: AsRef<Path>>(path: P, pattern: &str) -> DynResult<Vec<String>> {
+    let input = read_file_to_string(path)?;
+    find_matches(&input, pattern)
+}
+
+pub fn find_matches(input: &str, pattern: &str) -> DynResult<Vec<String>> {
+    let pattern_matcher = Regex::new(pattern)?;
+    let result = pattern_matcher
+        .find_iter(input)
+        .map(|m| m.as_str().to_string())
+        .collect();
+    Ok(result)
+}
+
+/// Returns the 1-based column of the char starting at `byte_offset` in `line`.
+pub fn column_of(line: &str, byte_offset: usize) -> usize {
+    line[..byte_offset].chars().count() + 1
+}
+
+/// Records `path` as the file of `error` if it is a [`ParseError`] without a file yet.
+pub fn locate_error<P: AsRef<Path>>(mut error: Box<dyn Error>, path: P) -> Box<dyn Error> {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>()
+        && parse_error.file.is_none()
+    {
+        parse_error.file = Some(path.as_ref().to_path_buf());
+    }
+    error
+}
+
+/// Malformed input, located by its 1-based line and column.
+///
+/// If the line it occurred in is known, the error is displayed with that line and the message next
+/// to a caret under the column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+    pub source_line: Option<String>,
+}
+
+/// Longest part of a source line shown by a [`ParseError`].
+const SNIPPET_WIDTH: usize = 80;
+
+impl ParseError {
+    pub fn new<T: ToString, M: Into<String>>(
+        line: usize,
+        column: usize,
+        text: T,
+        message: M,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+            source_line: None,
+        }
+    }
+
+    /// Remembers the text of the line the error occurred in, unless it is known already.
+    pub fn with_source_line(mut self, source_line: &str) -> Self {
+        self.source_line.get_or_insert_with(|| source_line.to_string());
+        self
+    }
+
+    fn write_location(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file.display(), self.line, self.column),
+            None => write!(f, "line {}, column {}", self.line, self.column),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(source_line) = &self.source_line else {
+            write!(f, "Invalid input at ")?;
+            self.write_location(f)?;
+            write!(f, ": {}", self.message)?;
+            if !self.text.is_empty() {
+                write!(f, " (found '{}')", self.text)?;
+            }
+            return Ok(());
+        };
+
+        let chars: Vec<char> = source_line.chars().collect();
+        let caret_index = self.column.saturating_sub(1);
+        let start = caret_index
+            .saturating_sub(SNIPPET_WIDTH / 2)
+            .min(chars.len().saturating_sub(SNIPPET_WIDTH));
+        let end = chars.len().min(start + SNIPPET_WIDTH);
+        let prefix = if start > 0 { "..." } else { "" };
+        let suffix = if end < chars.len() { "..." } else { "" };
+        let snippet: String = chars[start..end].iter().collect();
+        // Tabs are kept in the padding, so that the caret lines up however wide they are shown.
+        let padding: String = prefix
+            .chars()
+            .chain(chars[start..caret_index.min(end)].iter().copied())
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .chain(std::iter::repeat_n(' ', caret_index.saturating_sub(end)))
+            .collect();
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        write!(f, "Invalid input at ")?;
+        self.write_location(f)?;
+        writeln!(f)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {prefix}{snippet}{suffix}", self.line)?;
+        write!(f, "{gutter} | {padding}^ {}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_zero_and_leading_zeros() {
+        let numbers: Vec<u32> =
+            parse_numbers_in_line("0 007 10", 1, NumberFormat::Unsigned).unwrap();
+        assert_eq!(numbers, [0, 7, 10]);
+    }
+
+    #[test]
+    fn signed_format_reads_signs() {
+        let numbers: Vec<i32> = parse_numbers_in_line("3-5 +2", 1, NumberFormat::Signed).unwrap();
+        assert_eq!(numbers, [3, -5, 2]);
+    }
+
+    #[test]
+    fn unsigned_format_treats_signs_as_separators() {
+        let numbers: Vec<u32> = parse_numbers_in_line("3-5 +2", 1, NumberFormat::Unsigned).unwrap();
+        assert_eq!(numbers, [3, 5, 2]);
+    }
+
+    #[test]
+    fn parses_wide_integers() {
+        let line = u64::MAX.to_string();
+        let numbers: Vec<u64> = parse_numbers_in_line(&line, 1, NumberFormat::Unsigned).unwrap();
+        assert_eq!(numbers, [u64::MAX]);
+    }
+
+    #[test]
+    fn reports_overflow_as_parse_error() {
+        let error = parse_numbers_in_line::<u8>("1 2 256", 4, NumberFormat::Unsigned).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 5));
+        assert_eq!(error.text, "256");
+        assert!(error.message.starts_with("Invalid number"));
+    }
+
+    #[test]
+    fn reports_negative_unsigned_as_parse_error() {
+        let error = parse_numbers_in_line::<u32>("1 -2", 1, NumberFormat::Signed).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "-2"));
+    }
+
+    fn section_lines(input: &str) -> Vec<(usize, &str)> {
+        split_sections(input)
+            .iter()
+            .map(|section| (section.first_line, section.text))
+            .collect()
+    }
+
+    #[test]
+    fn splits_at_whitespace_only_lines() {
+        assert_eq!(section_lines("a\nb\n  \t\nc\n"), [(1, "a\nb"), (4, "c")]);
+    }
+
+    #[test]
+    fn merges_several_blank_lines() {
+        assert_eq!(section_lines("a\n\n\n\nb"), [(1, "a"), (5, "b")]);
+    }
+
+    #[test]
+    fn skips_leading_blank_lines() {
+        assert_eq!(section_lines("\n\na"), [(3, "a")]);
+        assert!(section_lines("\n \n").is_empty());
+    }
+
+    #[test]
+    fn reports_too_few_sections_at_the_last_line() {
+        let error = split_sections_exact::<2>("a\nb\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(
+            error.message,
+            "Expected 2 sections separated by blank lines, found 1"
+        );
+    }
+
+    #[test]
+    fn reports_too_many_sections_at_the_first_extra_one() {
+        let error = split_sections_exact::<2>("a\n\nb\n\n\nc\n").unwrap_err();
+        assert_eq!(error.line, 6);
+        assert_eq!(
+            error.message,
+            "Expected 2 sections separated by blank lines, found 3"
+        );
+    }
+
+    #[test]
+    fn section_parse_shifts_error_lines() {
+        let [_, updates] = split_sections_exact::<2>("1|2\n\n1,2\n3,x,99999999999\n").unwrap();
+        let error = updates
+            .parse(|text| parse_numbers_from_str::<u8>(text, NumberFormat::Unsigned))
+            .unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (4, 5));
+    }
+
+    #[test]
+    fn displays_location_without_source_line() {
+        let mut error = ParseError::new(2, 3, "x", "Expected a number");
+        assert_eq!(
+            error.to_string(),
+            "Invalid input at line 2, column 3: Expected a number (found 'x')"
+        );
+        error.file = Some(PathBuf::from("input.txt"));
+        assert_eq!(
+            error.to_string(),
+            "Invalid input at input.txt:2:3: Expected a number (found 'x')"
+        );
+    }
+
+    #[test]
+    fn displays_short_line_with_caret() {
+        let error = ParseError::new(3, 5, "x", "Expected a number").with_source_line("1 2 x 4");
+        assert_eq!(
+            error.to_string(),
+            "Invalid input at line 3, column 5\n  |\n3 | 1 2 x 4\n  |     ^ Expected a number"
+        );
+    }
+
+    #[test]
+    fn displays_middle_of_long_line() {
+        let line = format!("{}x{}", "a".repeat(99), "b".repeat(100));
+        let error = ParseError::new(1, 100, "x", "Expected a number").with_source_line(&line);
+        let display = error.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        let snippet = format!("1 | ...{}x{}...", "a".repeat(40), "b".repeat(39));
+        assert_eq!(lines[2], snippet);
+        assert_eq!(
+            lines[3],
+            format!("  | {}^ Expected a number", " ".repeat(43))
+        );
+    }
+
+    #[test]
+    fn keeps_tabs_in_caret_padding() {
+        let error = ParseError::new(1, 5, "c", "Expected a number").with_source_line("\tab\tc");
+        let display = error.to_string();
+        assert_eq!(
+            display.lines().last(),
+            Some("  | \t  \t^ Expected a number")
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const BOM: char = '\u{feff}';
+
+/// Width of a tab stop when expanding tabs, or 0 to keep tabs.
+static TAB_WIDTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Makes every reader expand tabs to the next multiple of `tab_width` columns, or keep them if it
+/// is `None`.
+pub fn set_tab_width(tab_width: Option<usize>) {
+    TAB_WIDTH.store(tab_width.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// What [`normalize`] changed in an input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NormalizationReport {
+    pub removed_bom: bool,
+    pub converted_line_endings: usize,
+    pub trimmed_lines: usize,
+    pub removed_blank_lines: usize,
+    pub expanded_tabs: usize,
+}
+
+impl NormalizationReport {
+    pub fn is_unchanged(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for NormalizationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut changes = vec![];
+        if self.removed_bom {
+            changes.push("removed the byte order mark".to_string());
+        }
+        for (count, change) in [
+            (self.converted_line_endings, "converted line endings"),
+            (self.trimmed_lines, "trimmed lines"),
+            (self.removed_blank_lines, "removed trailing blank lines"),
+            (self.expanded_tabs, "expanded tabs"),
+        ] {
+            if count > 0 {
+                changes.push(format!("{change}: {count}"));
+            }
+        }
+        if changes.is_empty() {
+            write!(f, "unchanged")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+/// Strips a byte order mark, converts `\r\n` and `\r` line endings to `\n`, trims trailing
+/// whitespace of every line and trailing blank lines, and expands tabs if a tab width is set.
+///
+/// A non-empty result ends with exactly one `\n`.
+pub fn normalize(input: &str) -> (String, NormalizationReport) {
+    normalize_with(input, tab_width())
+}
+
+/// Like [`normalize`], but expands tabs to multiples of `tab_width` columns instead of the width set
+/// with [`set_tab_width`], or keeps them if it is `None`.
+pub fn normalize_with(input: &str, tab_width: Option<usize>) -> (String, NormalizationReport) {
+    let mut report = NormalizationReport::default();
+    let input = match input.strip_prefix(BOM) {
+        Some(rest) => {
+            report.removed_bom = true;
+            rest
+        }
+        None => input,
+    };
+
+    let mut result = String::with_capacity(input.len());
+    let mut blank_lines = 0;
+    let mut lines = input.split('\n').peekable();
+    while let Some(mut line) = lines.next() {
+        if lines.peek().is_none() && line.is_empty() {
+            break;
+        }
+        if let Some(rest) = line.strip_suffix('\r') {
+            report.converted_line_endings += 1;
+            line = rest;
+        }
+        for line in line.split('\r') {
+            let line = normalize_line(line, tab_width, &mut report);
+            if line.is_empty() {
+                blank_lines += 1;
+            } else {
+                for _ in 0..blank_lines {
+                    result.push('\n');
+                }
+                blank_lines = 0;
+                result.push_str(&line);
+                result.push('\n');
+            }
+        }
+        report.converted_line_endings += line.matches('\r').count();
+    }
+    report.removed_blank_lines = blank_lines;
+    (result, report)
+}
+
+fn tab_width() -> Option<usize> {
+    Some(TAB_WIDTH.load(Ordering::Relaxed)).filter(|tab_width| *tab_width > 0)
+}
+
+fn normalize_line(
+    line: &str,
+    tab_width: Option<usize>,
+    report: &mut NormalizationReport,
+) -> String {
+    let trimmed = line.trim_end();
+    if trimmed.len() != line.len() {
+        report.trimmed_lines += 1;
+    }
+    let Some(tab_width) = tab_width.filter(|_| trimmed.contains('\t')) else {
+        return trimmed.to_string();
+    };
+    let mut result = String::with_capacity(trimmed.len());
+    let mut column = 0;
+    for c in trimmed.chars() {
+        if c == '\t' {
+            report.expanded_tabs += 1;
+            let spaces = tab_width - column % tab_width;
+            result.extend(std::iter::repeat_n(' ', spaces));
+            column += spaces;
+        } else {
+            result.push(c);
+            column += 1;
+        }
+    }
+    result
+}
+
+/// Lines of a reader, normalized one at a time like [`normalize`] does for a whole input.
+///
+/// Lines end at `\n`, `\r\n` or `\r`. Trailing blank lines cannot be told apart from blank lines
+/// between sections without reading ahead, so they are kept. The report is logged once the reader
+/// is exhausted.
+pub struct NormalizedLines<R> {
+    reader: R,
+    is_first_line: bool,
+    /// Whether the last line ended with `\r`, so that a `\n` directly after it belongs to it.
+    after_carriage_return: bool,
+    tab_width: Option<usize>,
+    report: NormalizationReport,
+}
+
+impl<R: BufRead> NormalizedLines<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            is_first_line: true,
+            after_carriage_return: false,
+            tab_width: tab_width(),
+            report: NormalizationReport::default(),
+        }
+    }
+
+    /// Reads the next line without its line ending, or `None` at the end of the input.
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = vec![];
+        loop {
+            let buffer = self.reader.fill_buf()?;
+            if buffer.is_empty() {
+                self.after_carriage_return = false;
+                if line.is_empty() {
+                    return Ok(None);
+                }
+                break;
+            }
+            if self.after_carriage_return {
+                self.after_carriage_return = false;
+                if buffer[0] == b'\n' {
+                    self.reader.consume(1);
+                    continue;
+                }
+            }
+            match buffer.iter().position(|b| matches!(b, b'\n' | b'\r')) {
+                Some(index) => {
+                    line.extend_from_slice(&buffer[..index]);
+                    if buffer[index] == b'\r' {
+                        self.report.converted_line_endings += 1;
+                        self.after_carriage_return = true;
+                    }
+                    self.reader.consume(index + 1);
+                    break;
+                }
+                None => {
+                    let length = buffer.len();
+                    line.extend_from_slice(buffer);
+                    self.reader.consume(length);
+                }
+            }
+        }
+        String::from_utf8(line)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl<R: BufRead> Iterator for NormalizedLines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.read_line() {
+            Ok(Some(line)) => line,
+            Ok(None) => {
+                if !self.report.is_unchanged() {
+                    crate::debug!("Normalized the input: {}", self.report);
+                    self.report = NormalizationReport::default();
+                }
+                return None;
+            }
+            Err(e) => return Some(Err(e)),
+        };
+        let mut line = line.as_str();
+        if self.is_first_line {
+            self.is_first_line = false;
+            if let Some(rest) = line.strip_prefix(BOM) {
+                self.report.removed_bom = true;
+                line = rest;
+            }
+        }
+        Some(Ok(normalize_line(line, self.tab_width, &mut self.report)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    fn stream(input: &str) -> Vec<String> {
+        // A tiny buffer splits `\r\n` between two reads.
+        NormalizedLines::new(BufReader::with_capacity(1, input.as_bytes()))
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn removes_bom() {
+        let (result, report) = normalize("\u{feff}1 2\n");
+        assert_eq!(result, "1 2\n");
+        assert!(report.removed_bom);
+        assert_eq!(stream("\u{feff}1 2\n"), ["1 2"]);
+    }
+
+    #[test]
+    fn converts_crlf() {
+        let (result, report) = normalize("a\r\nb\r\n");
+        assert_eq!(result, "a\nb\n");
+        assert_eq!(report.converted_line_endings, 2);
+        assert_eq!(stream("a\r\nb\r\n"), ["a", "b"]);
+    }
+
+    #[test]
+    fn converts_bare_cr() {
+        let (result, report) = normalize("a\rb\r\rc");
+        assert_eq!(result, "a\nb\n\nc\n");
+        assert_eq!(report.converted_line_endings, 3);
+        assert_eq!(stream("a\rb\r\rc"), ["a", "b", "", "c"]);
+    }
+
+    #[test]
+    fn trims_trailing_whitespace_and_blank_lines() {
+        let (result, report) = normalize("a  \n\nb\t\n\n \n");
+        assert_eq!(result, "a\n\nb\n");
+        assert_eq!(report.trimmed_lines, 3);
+        assert_eq!(report.removed_blank_lines, 2);
+    }
+
+    #[test]
+    fn keeps_unchanged_input() {
+        let (result, report) = normalize("a\n\nb\n");
+        assert_eq!(result, "a\n\nb\n");
+        assert!(report.is_unchanged());
+    }
+
+    #[test]
+    fn expands_tabs_to_tab_stops() {
+        let (result, report) = normalize_with("a\tbc\td\n\te\n", Some(4));
+        assert_eq!(result, "a   bc  d\n    e\n");
+        assert_eq!(report.expanded_tabs, 3);
+    }
+}
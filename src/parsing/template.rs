@@ -0,0 +1,209 @@
+use super::{ParseError, column_of};
+use crate::DynResult;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Fixed shape of the lines of an input, where every `{}` stands for a field, e.g. `"{}|{}"` or
+/// `"p={},{} v={},{}"`.
+pub struct LineTemplate {
+    literals: Vec<String>,
+}
+
+impl LineTemplate {
+    /// # Panics
+    ///
+    /// If two fields are not separated by a literal, because their boundary would be ambiguous.
+    pub fn new(template: &str) -> Self {
+        let literals: Vec<String> = template.split("{}").map(str::to_string).collect();
+        let inner_literals = &literals[1..literals.len().max(2) - 1];
+        assert!(
+            inner_literals.iter().all(|l| !l.is_empty()),
+            "Fields of the template '{template}' must be separated by literals"
+        );
+        Self { literals }
+    }
+
+    pub fn field_count(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    /// Splits `line` into the texts of its fields. Every field ends at the first occurrence of
+    /// the literal following it.
+    pub fn fields<'a>(
+        &self,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Vec<Field<'a>>, ParseError> {
+        let mismatch = |offset: usize, message: String| {
+            ParseError::new(line_number, column_of(line, offset), line, message)
+                .with_source_line(line)
+        };
+        let mut offset = self.literals[0].len();
+        if !line.starts_with(&self.literals[0]) {
+            return Err(mismatch(0, format!("Expected '{}'", self.literals[0])));
+        }
+        let mut fields = vec![];
+        for (index, literal) in self.literals.iter().enumerate().skip(1) {
+            let rest = &line[offset..];
+            let expected = || format!("Expected '{literal}'");
+            let field_length = if index == self.literals.len() - 1 {
+                rest.strip_suffix(literal.as_str())
+                    .ok_or_else(|| mismatch(line.len(), expected()))?
+                    .len()
+            } else {
+                rest.find(literal.as_str()).ok_or_else(|| {
+                    mismatch(offset + expected_position(rest, literal), expected())
+                })?
+            };
+            fields.push(Field {
+                text: &rest[..field_length],
+                line: line_number,
+                column: column_of(line, offset),
+            });
+            offset += field_length + literal.len();
+        }
+        if offset != line.len() {
+            return Err(mismatch(offset, "Expected the end of the line".to_string()));
+        }
+        Ok(fields)
+    }
+
+    /// Parses a single line, e.g. `template.parse_line::<(i32, i32)>("3|4", 1)`.
+    pub fn parse_line<T: FromFields>(
+        &self,
+        line: &str,
+        line_number: usize,
+    ) -> Result<T, ParseError> {
+        if T::FIELD_COUNT != self.field_count() {
+            return Err(ParseError::new(
+                line_number,
+                1,
+                line,
+                format!(
+                    "The template has {} fields, but {} were expected",
+                    self.field_count(),
+                    T::FIELD_COUNT
+                ),
+            ));
+        }
+        T::from_fields(&self.fields(line, line_number)?).map_err(|e| e.with_source_line(line))
+    }
+
+    /// Lazily parses every line of `input`.
+    pub fn parse_lines<'a, T: FromFields>(
+        &'a self,
+        input: &'a str,
+    ) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| self.parse_line(line, line_index + 1))
+    }
+
+    pub fn parse_all<T: FromFields>(&self, input: &str) -> DynResult<Vec<T>> {
+        Ok(self.parse_lines(input).collect::<Result<_, _>>()?)
+    }
+}
+
+/// Where `literal` was most likely expected in `rest`: the first occurrence of its longest prefix
+/// that occurs at all, or the start of `rest` if not even its first char does.
+fn expected_position(rest: &str, literal: &str) -> usize {
+    literal
+        .char_indices()
+        .map(|(index, c)| index + c.len_utf8())
+        .rev()
+        .find_map(|prefix_length| rest.find(&literal[..prefix_length]))
+        .unwrap_or(0)
+}
+
+/// Text of one field of a line, located by its 1-based line and column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Field<'_> {
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|e| {
+            ParseError::new(
+                self.line,
+                self.column,
+                self.text,
+                format!("Invalid field: {e}"),
+            )
+        })
+    }
+}
+
+/// Value that can be built from the fields of a [`LineTemplate`]. Implemented for tuples of up to
+/// six parsable values; structs can implement it by parsing each field with [`Field::parse`].
+pub trait FromFields: Sized {
+    const FIELD_COUNT: usize;
+
+    fn from_fields(fields: &[Field]) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_fields_for_tuple {
+    ($count:literal; $($t:ident $index:literal),*) => {
+        impl<$($t),*> FromFields for ($($t,)*)
+        where
+            $($t: FromStr, $t::Err: Display),*
+        {
+            const FIELD_COUNT: usize = $count;
+
+            fn from_fields(fields: &[Field]) -> Result<Self, ParseError> {
+                Ok(($(fields[$index].parse::<$t>()?,)*))
+            }
+        }
+    };
+}
+
+impl_from_fields_for_tuple!(1; A 0);
+impl_from_fields_for_tuple!(2; A 0, B 1);
+impl_from_fields_for_tuple!(3; A 0, B 1, C 2);
+impl_from_fields_for_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_fields_for_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_fields_for_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fields() {
+        let template = LineTemplate::new("p={},{} v={},{}");
+        let fields: (i32, i32, i32, i32) = template.parse_line("p=0,4 v=3,-3", 1).unwrap();
+        assert_eq!(fields, (0, 4, 3, -3));
+    }
+
+    #[test]
+    fn reports_missing_literal_where_it_was_expected() {
+        let template = LineTemplate::new("p={},{} v={},{}");
+        let error = template.fields("p=0,4 w=3,-3", 2).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.message, "Expected ' v='");
+    }
+
+    #[test]
+    fn reports_missing_literal_at_the_field_without_any_part_of_it() {
+        let error = LineTemplate::new("{}|{}").fields("47-53", 1).unwrap_err();
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn rejects_leftover_text_without_fields() {
+        let template = LineTemplate::new("do()");
+        assert!(template.fields("do()", 1).unwrap().is_empty());
+        let error = template.fields("do()x", 1).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (5, "Expected the end of the line")
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::DynResult;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CACHE_DIR: &str = "input";
+/// Identifies this tool in every request to the puzzle server.
+pub const USER_AGENT: &str = "github.com/DennisPruene/AdventOfCode2024";
+
+/// Source of puzzle inputs that are not available locally.
+pub trait InputProvider: Sync {
+    fn fetch(&self, day: u8) -> DynResult<String>;
+}
+
+/// Downloads inputs from `<base_url>/2024/day/<day>/input`, authenticated by a session cookie.
+pub struct HttpInputProvider {
+    base_url: String,
+    session: String,
+}
+
+impl HttpInputProvider {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Reads the base URL from `AOC_BASE_URL` and the session token from `AOC_SESSION`.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var("AOC_SESSION").ok()?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Some(Self::new(&base_url, &session))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+}
+
+impl InputProvider for HttpInputProvider {
+    fn fetch(&self, day: u8) -> DynResult<String> {
+        let url = format!("{}/2024/day/{day}/input", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| {
+                ProviderError(format!("Could not download the input of day {day}: {e}"))
+            })?;
+        Ok(response.into_string()?)
+    }
+}
+
+/// Keeps downloaded inputs in `<cache_dir>/dayN/input.txt`, so every input is fetched only once.
+pub struct InputCache<P> {
+    cache_dir: PathBuf,
+    provider: Option<P>,
+}
+
+impl<P: InputProvider> InputCache<P> {
+    pub fn new<D: AsRef<Path>>(cache_dir: D, provider: Option<P>) -> Self {
+        Self {
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            provider,
+        }
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{day}")).join("input.txt")
+    }
+
+    /// Returns `local_path` if it exists, the cached input otherwise, downloading it if needed.
+    pub fn resolve(&self, day: u8, local_path: &Path) -> DynResult<PathBuf> {
+        if local_path.exists() {
+            return Ok(local_path.to_path_buf());
+        }
+        let cached_path = self.cached_path(day);
+        if cached_path.exists() {
+            return Ok(cached_path);
+        }
+        let provider = self.provider.as_ref().ok_or_else(|| {
+            ProviderError(format!(
+                "{} is missing and no input provider is configured (set AOC_SESSION)",
+                local_path.display()
+            ))
+        })?;
+        let input = provider.fetch(day)?;
+        fs::create_dir_all(cached_path.parent().unwrap())?;
+        let partial_path = cached_path.with_extension("txt.part");
+        fs::write(&partial_path, input)?;
+        fs::rename(&partial_path, &cached_path)?;
+        Ok(cached_path)
+    }
+}
+
+impl InputCache<HttpInputProvider> {
+    /// Uses `AOC_CACHE_DIR` as cache directory and an [`HttpInputProvider`] configured from the
+    /// environment.
+    pub fn from_env() -> Self {
+        let cache_dir = env::var("AOC_CACHE_DIR").unwrap_or_else(|_| DEFAULT_CACHE_DIR.to_string());
+        Self::new(cache_dir, HttpInputProvider::from_env())
+    }
+}
+
+#[derive(Debug)]
+pub struct ProviderError(String);
+
+impl Display for ProviderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ProviderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        fetches: AtomicUsize,
+    }
+
+    impl InputProvider for &CountingProvider {
+        fn fetch(&self, day: u8) -> DynResult<String> {
+            self.fetches.fetch_add(1, Ordering::Relaxed);
+            Ok(format!("input of day {day}\n"))
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-provider-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn prefers_local_input() {
+        let dir = temp_dir("local");
+        let local_path = dir.join("input.txt");
+        fs::write(&local_path, "local").unwrap();
+        let provider = CountingProvider {
+            fetches: AtomicUsize::new(0),
+        };
+        let cache = InputCache::new(dir.join("cache"), Some(&provider));
+        assert_eq!(cache.resolve(1, &local_path).unwrap(), local_path);
+        assert_eq!(provider.fetches.load(Ordering::Relaxed), 0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn downloads_missing_input_once() {
+        let dir = temp_dir("download");
+        let local_path = dir.join("missing.txt");
+        let provider = CountingProvider {
+            fetches: AtomicUsize::new(0),
+        };
+        let cache = InputCache::new(dir.join("cache"), Some(&provider));
+        let cached_path = dir.join("cache").join("day7").join("input.txt");
+
+        assert_eq!(cache.resolve(7, &local_path).unwrap(), cached_path);
+        assert_eq!(
+            fs::read_to_string(&cached_path).unwrap(),
+            "input of day 7\n"
+        );
+        assert_eq!(cache.resolve(7, &local_path).unwrap(), cached_path);
+        assert_eq!(provider.fetches.load(Ordering::Relaxed), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fails_without_provider() {
+        let dir = temp_dir("no-provider");
+        let cache: InputCache<&CountingProvider> = InputCache::new(dir.join("cache"), None);
+        let error = cache.resolve(1, &dir.join("missing.txt")).unwrap_err();
+        assert!(error.is::<ProviderError>());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+use crate::DynResult;
+use crate::log::span;
+use crate::parsing::{locate_error, open_input, read_input};
+use crate::solution::{Answer, Part, Solver, StreamingSolution};
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe, catch_unwind};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, Once};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Outcome of solving one part of one day.
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+/// Reads and parses the input and solves every part in `parts`, collecting errors and panics
+/// instead of returning early.
+pub fn run_day<I>(day: u8, solver: &dyn Solver, path: &Path, parts: I) -> Vec<PartResult>
+where
+    I: IntoIterator<Item = Part>,
+{
+    let _day_span = span(format!("day{day}"));
+    crate::info!("Solving Day {day} on the input {}", path.display());
+    let parts = parts.into_iter();
+    let input = match catch_panic(|| {
+        solver
+            .parse(&read_input(path)?)
+            .map_err(|e| locate_error(e, path))
+    }) {
+        Ok(input) => input,
+        Err(e) => {
+            return parts
+                .map(|part| PartResult {
+                    day,
+                    part,
+                    input: path.to_path_buf(),
+                    answer: Err(e.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect();
+        }
+    };
+    parts
+        .map(|part| {
+            let _part_span = span(format!("part{part}"));
+            let start = Instant::now();
+            let answer = catch_panic(|| solver.solve(part, &input));
+            crate::debug!("Finished in {:.3?}", start.elapsed());
+            PartResult {
+                day,
+                part,
+                input: path.to_path_buf(),
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// Like [`run_day`], but streams the input into a single pass that solves both parts. Both parts
+/// report the duration of that pass.
+pub fn run_day_streaming<I>(
+    day: u8,
+    solver: &dyn StreamingSolution,
+    path: &Path,
+    parts: I,
+) -> Vec<PartResult>
+where
+    I: IntoIterator<Item = Part>,
+{
+    let _day_span = span(format!("day{day}"));
+    crate::info!("Streaming Day {day} from the input {}", path.display());
+    let start = Instant::now();
+    let answers = catch_panic(|| {
+        solver
+            .solve_streaming(&mut open_input(path)?)
+            .map_err(|e| locate_error(e, path))
+    });
+    let elapsed = start.elapsed();
+    crate::debug!("Finished in {elapsed:.3?}");
+    parts
+        .into_iter()
+        .map(|part| PartResult {
+            day,
+            part,
+            input: path.to_path_buf(),
+            answer: match (&answers, part) {
+                (Ok((answer, _)), Part::One) | (Ok((_, answer)), Part::Two) => Ok(answer.clone()),
+                (Err(e), _) => Err(e.clone()),
+            },
+            elapsed,
+        })
+        .collect()
+}
+
+/// Runs the given days on a pool of `jobs` threads and returns their results ordered like `days`.
+pub fn run_days_parallel(
+    days: &[(u8, &dyn Solver, PathBuf)],
+    parts: &[Part],
+    jobs: usize,
+) -> Vec<PartResult> {
+    let next_day = AtomicUsize::new(0);
+    let results: Mutex<Vec<Vec<PartResult>>> = Mutex::new(days.iter().map(|_| vec![]).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next_day.fetch_add(1, Ordering::Relaxed);
+                    let Some((day, solver, path)) = days.get(index) else {
+                        break;
+                    };
+                    let day_results = run_day(*day, *solver, path, parts.iter().copied());
+                    results.lock().unwrap()[index] = day_results;
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+thread_local! {
+    static IS_CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_PANIC_HOOK: Once = Once::new();
+
+/// Keeps the panic hook from printing panics that [`catch_panic`] reports as errors anyway. Other
+/// panics are still printed by the previous hook.
+fn install_quiet_panic_hook() {
+    QUIET_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING_PANICS.get() {
+                crate::debug!("Caught a panic: {info}");
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn catch_panic<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce() -> DynResult<T>,
+{
+    install_quiet_panic_hook();
+    let was_catching_panics = IS_CATCHING_PANICS.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(f));
+    IS_CATCHING_PANICS.set(was_catching_panics);
+    match result {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+pub fn to_json(results: &[PartResult]) -> String {
+    let mut json = String::from("{\n  \"results\": [");
+    for (i, result) in results.iter().enumerate() {
+        let answer = match &result.answer {
+            Ok(Answer::Number(number)) => number.to_string(),
+            Ok(Answer::Text(text)) => json_string(text),
+            Err(_) => "null".to_string(),
+        };
+        let error = match &result.answer {
+            Ok(_) => "null".to_string(),
+            Err(e) => json_string(e),
+        };
+        let separator = if i == 0 { "" } else { "," };
+        write!(
+            json,
+            "{separator}\n    {{\"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {answer}, \"elapsed_ns\": {}, \"error\": {error}}}",
+            result.day,
+            result.part,
+            json_string(&result.input.display().to_string()),
+            result.elapsed.as_nanos(),
+        )
+        .unwrap();
+    }
+    json.push_str("\n  ]\n}");
+    json
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
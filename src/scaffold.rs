@@ -0,0 +1,123 @@
+use crate::cli::CliError;
+use advent_of_code_2024::DynResult;
+use std::fs;
+use std::path::Path;
+
+const MODULE_DECLARATIONS: &str = "src/lib.rs";
+const REGISTRY: &str = "src/solution.rs";
+
+/// Generates `src/dayN.rs`, registers it and creates the `input/dayN/` placeholders.
+pub fn new_day(day: u8) -> DynResult<()> {
+    let module_path = format!("src/day{day}.rs");
+    if Path::new(&module_path).exists() || Path::new(&format!("src/day{day}/mod.rs")).exists() {
+        return Err(CliError(format!("Day {day} already has a module")).into());
+    }
+
+    let main = fs::read_to_string(MODULE_DECLARATIONS)?;
+    let main = insert_after_last(
+        &main,
+        |line| line.starts_with("pub mod day"),
+        &format!("pub mod day{day};"),
+    )?;
+    let registry = fs::read_to_string(REGISTRY)?;
+    let registry = insert_after_last(
+        &registry,
+        |line| line.starts_with("use crate::day"),
+        &format!("use crate::day{day}::Day{day};"),
+    )?;
+    let registry = insert_after_last(
+        &registry,
+        |line| line.trim_start().starts_with('(') && line.contains(", &Day"),
+        &format!("    ({day}, &Day{day}),"),
+    )?;
+
+    fs::write(&module_path, module_template(day))?;
+    fs::write(MODULE_DECLARATIONS, main)?;
+    fs::write(REGISTRY, registry)?;
+    println!("Created {module_path} and registered Day{day}");
+
+    fs::create_dir_all(format!("input/day{day}"))?;
+    for file_name in ["input.txt", "example.txt"] {
+        let path = format!("input/day{day}/{file_name}");
+        if !Path::new(&path).exists() {
+            fs::write(&path, "")?;
+            println!("Created {path}");
+        }
+    }
+    println!(
+        "Paste the example into input/day{day}/example.txt and record its answers with \
+         `verify {day} --example --record`"
+    );
+    Ok(())
+}
+
+/// Inserts `line` after the last line matching `anchor`.
+fn insert_after_last<F>(content: &str, anchor: F, line: &str) -> DynResult<String>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut lines: Vec<&str> = content.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| anchor(l))
+        .ok_or_else(|| CliError(format!("Found no place to insert '{line}'")))?;
+    lines.insert(last + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn module_template(day: u8) -> String {
+    format!(
+        "\
+use crate::DynResult;
+use crate::solution::{{Answer, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = String;
+
+    // Remove once both parts are solved, so that a plain `run` or `verify` includes this day.
+    const SOLVED: bool = false;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input> {{
+        Ok(input.to_string())
+    }}
+
+    fn part1(&self, _input: &Self::Input) -> DynResult<Answer> {{
+        Err(\"Day {day} Part 1 has not been solved yet\".into())
+    }}
+
+    fn part2(&self, _input: &Self::Input) -> DynResult<Answer> {{
+        Err(\"Day {day} Part 2 has not been solved yet\".into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::answers::AnswerStore;
+    use crate::parsing::read_input;
+    use crate::solution::Part;
+
+    const EXAMPLE: &str = \"input/day{day}/example.txt\";
+
+    /// Checks the parts whose example answers were recorded with `verify {day} --example --record`.
+    #[test]
+    fn solves_example() {{
+        let store = AnswerStore::load({day}).unwrap();
+        let input = Day{day}.parse(&read_input(EXAMPLE).unwrap()).unwrap();
+        for part in [Part::One, Part::Two] {{
+            let Some(expected) = store.get(EXAMPLE, part) else {{
+                continue;
+            }};
+            let answer = match part {{
+                Part::One => Day{day}.part1(&input),
+                Part::Two => Day{day}.part2(&input),
+            }};
+            assert_eq!(answer.unwrap().to_string(), expected, \"Part {{part}}\");
+        }}
+    }}
+}}
+"
+    )
+}
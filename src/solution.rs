@@ -0,0 +1,184 @@
+use crate::DynResult;
+use crate::day1::Day1;
+use crate::day2::Day2;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use std::any::Any;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+static SOLUTIONS: &[(u8, &dyn Solver)] = &[
+    (1, &Day1),
+    (2, &Day2),
+    (3, &Day3),
+    (4, &Day4),
+    (5, &Day5),
+    (6, &Day6),
+];
+
+static STREAMING_SOLUTIONS: &[(u8, &dyn StreamingSolution)] = &[(1, &Day1), (2, &Day2)];
+
+pub fn get_solution(day: u8) -> Option<&'static dyn Solver> {
+    SOLUTIONS
+        .iter()
+        .find(|(solved_day, _)| *solved_day == day)
+        .map(|(_, solver)| *solver)
+}
+
+/// Days that are run when no day is selected, which leaves out days that were only scaffolded.
+pub fn solved_days() -> impl Iterator<Item = u8> {
+    SOLUTIONS
+        .iter()
+        .filter(|(_, solver)| solver.is_solved())
+        .map(|(day, _)| *day)
+}
+
+pub fn get_streaming_solution(day: u8) -> Option<&'static dyn StreamingSolution> {
+    STREAMING_SOLUTIONS
+        .iter()
+        .find(|(solved_day, _)| *solved_day == day)
+        .map(|(_, solver)| *solver)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().parse() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(s.trim().to_string()),
+        })
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A puzzle solution, split into parsing the input text and solving each part on the parsed input.
+pub trait Solution {
+    type Input;
+
+    /// False for a day that was scaffolded but not solved yet.
+    const SOLVED: bool = true;
+
+    fn parse(&self, input: &str) -> DynResult<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> DynResult<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> DynResult<Answer>;
+}
+
+/// Parsed input of a [`Solver`], whose concrete type is only known to the solver that produced it.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// Type-erased [`Solution`], so that solutions with different inputs can live in one registry.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> DynResult<ParsedInput>;
+
+    fn solve(&self, part: Part, input: &ParsedInput) -> DynResult<Answer>;
+
+    fn is_solved(&self) -> bool;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> DynResult<ParsedInput> {
+        Ok(ParsedInput(Box::new(Solution::parse(self, input)?)))
+    }
+
+    fn solve(&self, part: Part, input: &ParsedInput) -> DynResult<Answer> {
+        let input = input
+            .0
+            .downcast_ref()
+            .expect("Parsed input was produced by a different solver");
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn is_solved(&self) -> bool {
+        S::SOLVED
+    }
+}
+
+/// Solution that reads its input line by line instead of parsing it as a whole, so that it runs in
+/// bounded memory on arbitrarily large inputs.
+pub trait StreamingSolution: Sync {
+    /// Solves both parts in a single pass over `reader`.
+    fn solve_streaming(&self, reader: &mut dyn BufRead) -> DynResult<(Answer, Answer)>;
+}
@@ -0,0 +1,391 @@
+use crate::DynResult;
+use crate::provider::{HttpInputProvider, USER_AGENT};
+use crate::solution::{Answer, Part};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+static ARTICLE_MATCHER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static TAG_MATCHER: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+static LEFT_TO_WAIT_MATCHER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"you have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+static WAIT_MINUTES_MATCHER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"please wait (one|\d+) minutes? before trying again").unwrap());
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// The server is not expecting an answer for this part. This means it was solved already, but
+    /// also that part 2 was submitted before part 1 was solved.
+    AlreadySolved,
+    Wait,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Wait => "wait",
+        }
+    }
+
+    fn from_str(verdict: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Incorrect,
+            Verdict::AlreadySolved,
+            Verdict::Wait,
+        ]
+        .into_iter()
+        .find(|v| v.as_str() == verdict)
+    }
+
+    /// Whether the submitted answer was rejected as wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The server's reaction to a submission: the verdict and how long to wait before the next one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmissionResponse {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+impl SubmissionResponse {
+    pub fn parse(html: &str) -> DynResult<Self> {
+        let article = ARTICLE_MATCHER
+            .captures(html)
+            .map(|c| c.get(1).unwrap().as_str())
+            .unwrap_or(html);
+        let message = TAG_MATCHER.replace_all(article, "").to_lowercase();
+        let verdict = if message.contains("that's the right answer") {
+            Verdict::Correct
+        } else if message.contains("that's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            }
+        } else if message.contains("you gave an answer too recently") {
+            Verdict::Wait
+        } else if message.contains("you don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            return Err(SubmissionError(format!("Unexpected response: {}", message.trim())).into());
+        };
+
+        let wait = if let Some(captures) = LEFT_TO_WAIT_MATCHER.captures(&message) {
+            let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse())?;
+            let seconds: u64 = captures.get(2).unwrap().as_str().parse()?;
+            Some(Duration::from_secs(minutes * 60 + seconds))
+        } else if let Some(captures) = WAIT_MINUTES_MATCHER.captures(&message) {
+            let minutes = match captures.get(1).unwrap().as_str() {
+                "one" => 1,
+                minutes => minutes.parse()?,
+            };
+            Some(Duration::from_secs(minutes * 60))
+        } else {
+            None
+        };
+        Ok(Self { verdict, wait })
+    }
+}
+
+/// Posts answers to `<base_url>/2024/day/<day>/answer`.
+pub struct SubmissionClient {
+    base_url: String,
+    session: String,
+}
+
+impl SubmissionClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Uses the same `AOC_BASE_URL` and `AOC_SESSION` configuration as the input provider.
+    pub fn from_env() -> Option<Self> {
+        HttpInputProvider::from_env().map(|p| Self::new(p.base_url(), p.session()))
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> DynResult<SubmissionResponse> {
+        let url = format!("{}/2024/day/{day}/answer", self.base_url);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(|e| SubmissionError(format!("Could not submit the answer: {e}")))?;
+        SubmissionResponse::parse(&response.into_string()?)
+    }
+}
+
+/// Past submissions of one day, stored in `input/dayN/submissions.txt`.
+///
+/// Every line has the form `<unix time> <part> <verdict> <seconds to wait> <answer>`.
+pub struct SubmissionLog {
+    path: PathBuf,
+    entries: Vec<SubmissionEntry>,
+}
+
+pub struct SubmissionEntry {
+    pub time: u64,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub wait: u64,
+    pub answer: String,
+}
+
+impl SubmissionLog {
+    pub fn load(day: u8) -> DynResult<Self> {
+        let path = PathBuf::from(format!("input/day{day}/submissions.txt"));
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let mut entries = vec![];
+        for (line_index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = Self::parse_entry(line).ok_or_else(|| {
+                SubmissionError(format!(
+                    "Invalid line {} in {}: '{line}'",
+                    line_index + 1,
+                    path.display()
+                ))
+            })?;
+            entries.push(entry);
+        }
+        Ok(Self { path, entries })
+    }
+
+    fn parse_entry(line: &str) -> Option<SubmissionEntry> {
+        let mut fields = line.splitn(5, ' ');
+        Some(SubmissionEntry {
+            time: fields.next()?.parse().ok()?,
+            part: Part::from_number(fields.next()?.parse().ok()?)?,
+            verdict: Verdict::from_str(fields.next()?)?,
+            wait: fields.next()?.parse().ok()?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+
+    /// Explains why `answer` must not be sent, based on earlier responses of the server.
+    pub fn check(&self, part: Part, answer: &Answer) -> Option<String> {
+        let now = unix_time();
+        if let Some(until) = self.entries.iter().map(|e| e.time + e.wait).max()
+            && until > now
+        {
+            return Some(format!("The server asked to wait another {}s", until - now));
+        }
+        let answer_text = answer.to_string();
+        for entry in self.entries.iter().filter(|e| e.part == part) {
+            match entry.verdict {
+                Verdict::Correct => {
+                    return Some(format!("Part {part} was already solved"));
+                }
+                verdict if verdict.is_wrong() && entry.answer == answer_text => {
+                    return Some(format!(
+                        "{answer_text} was already submitted and is {verdict}"
+                    ));
+                }
+                _ => {}
+            }
+            if let (Answer::Number(number), Ok(bound)) = (answer, entry.answer.parse::<i128>()) {
+                let out_of_bounds = match entry.verdict {
+                    Verdict::TooHigh => *number >= bound,
+                    Verdict::TooLow => *number <= bound,
+                    _ => false,
+                };
+                if out_of_bounds {
+                    return Some(format!("{bound} was already {}", entry.verdict));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn append(
+        &mut self,
+        part: Part,
+        answer: &Answer,
+        response: &SubmissionResponse,
+    ) -> DynResult<()> {
+        let entry = SubmissionEntry {
+            time: unix_time(),
+            part,
+            verdict: response.verdict,
+            wait: response.wait.map_or(0, |w| w.as_secs()),
+            answer: answer.to_string(),
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{} {} {} {} {}",
+            entry.time, entry.part, entry.verdict, entry.wait, entry.answer
+        )?;
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[derive(Debug)]
+pub struct SubmissionError(String);
+
+impl Display for SubmissionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for SubmissionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(message: &str) -> SubmissionResponse {
+        SubmissionResponse::parse(&format!(
+            "<main>\n<article><p>{message}</p></article>\n</main>"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_right_answer() {
+        let response = parse(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+             closer to finding the Chief Historian.",
+        );
+        assert_eq!(response.verdict, Verdict::Correct);
+        assert_eq!(response.wait, None);
+    }
+
+    #[test]
+    fn parses_too_high() {
+        let response = parse(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+             you're using the full input data.  Please wait one minute before trying again.",
+        );
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn parses_too_low() {
+        let response = parse(
+            "That's not the right answer; your answer is too low.  Please wait 5 minutes before \
+             trying again.",
+        );
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(response.wait, Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn parses_rate_limit() {
+        let response = parse(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(response.verdict, Verdict::Wait);
+        assert_eq!(response.wait, Some(Duration::from_secs(65)));
+    }
+
+    #[test]
+    fn parses_already_solved() {
+        let response = parse(
+            "You don't seem to be solving the right level.  Did you already complete it? \
+             <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(response.verdict, Verdict::AlreadySolved);
+        assert_eq!(response.wait, None);
+    }
+
+    #[test]
+    fn rejects_unexpected_response() {
+        assert!(SubmissionResponse::parse("<article><p>Hello</p></article>").is_err());
+    }
+
+    fn log(entries: &[(Part, Verdict, &str)]) -> SubmissionLog {
+        SubmissionLog {
+            path: PathBuf::new(),
+            entries: entries
+                .iter()
+                .map(|(part, verdict, answer)| SubmissionEntry {
+                    time: 0,
+                    part: *part,
+                    verdict: *verdict,
+                    wait: 0,
+                    answer: answer.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn already_solved_does_not_block_the_part() {
+        let log = log(&[(Part::Two, Verdict::AlreadySolved, "7")]);
+        assert_eq!(log.check(Part::Two, &Answer::Number(7)), None);
+    }
+
+    #[test]
+    fn correct_answer_blocks_the_part() {
+        let log = log(&[(Part::One, Verdict::Correct, "7")]);
+        assert!(log.check(Part::One, &Answer::Number(8)).is_some());
+        assert_eq!(log.check(Part::Two, &Answer::Number(8)), None);
+    }
+
+    #[test]
+    fn bounds_block_answers_beyond_them() {
+        let log = log(&[
+            (Part::One, Verdict::TooHigh, "100"),
+            (Part::One, Verdict::TooLow, "10"),
+        ]);
+        assert!(log.check(Part::One, &Answer::Number(100)).is_some());
+        assert!(log.check(Part::One, &Answer::Number(5)).is_some());
+        assert_eq!(log.check(Part::One, &Answer::Number(50)), None);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::cli::{Cli, CliError, Parts};
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::answers::AnswerStore;
+use advent_of_code_2024::report::run_day;
+use advent_of_code_2024::solution::{Answer, Part, Solver};
+use advent_of_code_2024::submission::{SubmissionClient, SubmissionLog, Verdict};
+
+pub fn submit(cli: &Cli, day: u8, solver: &dyn Solver) -> DynResult<()> {
+    let part = match cli.parts {
+        Parts::One => Part::One,
+        Parts::Two => Part::Two,
+        Parts::Both => {
+            return Err(
+                CliError("`submit` expects the part to submit via --part".to_string()).into(),
+            );
+        }
+    };
+    let path = cli.input_path(day);
+    let answer: Answer = match &cli.answer {
+        Some(answer) => answer.parse()?,
+        None => run_day(day, solver, &path, [part]).remove(0).answer?,
+    };
+
+    let mut log = SubmissionLog::load(day)?;
+    if let Some(reason) = log.check(part, &answer) {
+        return Err(CliError(format!("Not submitting {answer}: {reason}")).into());
+    }
+    let client = SubmissionClient::from_env()
+        .ok_or_else(|| CliError("Set AOC_SESSION to submit answers".to_string()))?;
+    let response = client.submit(day, part, &answer)?;
+    log.append(part, &answer, &response)?;
+
+    if response.verdict == Verdict::Wait {
+        println!("Day {day} Part {part}: {answer} was not checked, the server is rate limiting");
+    } else {
+        println!("Day {day} Part {part}: {answer} is {}", response.verdict);
+    }
+    if let Some(wait) = response.wait {
+        println!("Wait {}s before submitting again", wait.as_secs());
+    }
+    if response.verdict == Verdict::Correct {
+        let mut store = AnswerStore::load(day)?;
+        store.record(&path, part, &answer);
+        store.save()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::cli::{Cli, CliError};
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::answers::AnswerStore;
+use advent_of_code_2024::report::{PartResult, run_day};
+use advent_of_code_2024::solution::{Answer, Solver};
+
+pub enum Verdict {
+    Pass(Answer),
+    Fail { expected: String, actual: String },
+    New(Answer),
+}
+
+pub fn verify(cli: &Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    let mut failures = 0;
+    for (day, solver) in solvers.iter() {
+        let path = cli.input_path(*day);
+        let mut store = AnswerStore::load(*day)?;
+        let mut has_new_answers = false;
+        for result in run_day(*day, *solver, &path, cli.parts.iter()) {
+            let part = result.part;
+            let verdict = verdict(result, store.get(&path, part));
+            let status = match &verdict {
+                Verdict::Pass(answer) => format!("PASS {answer}"),
+                Verdict::Fail { expected, actual } => {
+                    failures += 1;
+                    format!("FAIL expected {expected}, got {actual}")
+                }
+                Verdict::New(answer) => format!("NEW  {answer}"),
+            };
+            println!("Day {day} Part {part} on {}: {status}", path.display());
+            if let Verdict::New(answer) = verdict
+                && cli.record
+            {
+                store.record(&path, part, &answer);
+                has_new_answers = true;
+            }
+        }
+        if has_new_answers {
+            store.save()?;
+        }
+    }
+
+    if failures > 0 {
+        Err(CliError(format!("{failures} part(s) failed verification")).into())
+    } else {
+        Ok(())
+    }
+}
+
+fn verdict(result: PartResult, expected: Option<&str>) -> Verdict {
+    match (result.answer, expected) {
+        (Err(e), expected) => Verdict::Fail {
+            expected: expected.unwrap_or("an answer").to_string(),
+            actual: format!("error: {e}"),
+        },
+        (Ok(answer), None) => Verdict::New(answer),
+        (Ok(answer), Some(expected)) if answer.to_string() == expected => Verdict::Pass(answer),
+        (Ok(answer), Some(expected)) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: answer.to_string(),
+        },
+    }
+}
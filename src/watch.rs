@@ -0,0 +1,105 @@
+use crate::cli::{Cli, CliError, example_path};
+use advent_of_code_2024::DynResult;
+use advent_of_code_2024::info;
+use advent_of_code_2024::parsing::STDIN_PATH;
+use advent_of_code_2024::report::{PartResult, run_day};
+use advent_of_code_2024::solution::Solver;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One input of a day, which is re-run on that input whenever it changes.
+struct WatchedInput<'a> {
+    day: u8,
+    solver: &'a dyn Solver,
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    previous_results: Option<Vec<PartResult>>,
+}
+
+/// Re-runs every selected day whenever its input or its example changes, until interrupted.
+pub fn watch(cli: &Cli, solvers: &[(u8, &dyn Solver)]) -> DynResult<()> {
+    let mut watched_inputs = vec![];
+    for (day, solver) in solvers.iter() {
+        let path = cli.input_path(*day);
+        if path == Path::new(STDIN_PATH) {
+            return Err(CliError("Standard input cannot be watched".to_string()).into());
+        }
+        let example_path = example_path(*day);
+        let paths = if path == example_path {
+            vec![path]
+        } else {
+            vec![path, example_path]
+        };
+        for path in paths {
+            watched_inputs.push(WatchedInput {
+                day: *day,
+                solver: *solver,
+                path,
+                modified: None,
+                previous_results: None,
+            });
+        }
+    }
+
+    info!("Watching for changes, press Ctrl-C to stop");
+    loop {
+        for watched_input in watched_inputs.iter_mut() {
+            let modified = fs::metadata(&watched_input.path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            if modified.is_none() || modified == watched_input.modified {
+                continue;
+            }
+            if watched_input.modified.is_none() {
+                println!(
+                    "Day {} on {}:",
+                    watched_input.day,
+                    watched_input.path.display()
+                );
+            } else {
+                println!(
+                    "{} changed, re-running Day {}:",
+                    watched_input.path.display(),
+                    watched_input.day
+                );
+            }
+            watched_input.modified = modified;
+
+            let results = run_day(
+                watched_input.day,
+                watched_input.solver,
+                &watched_input.path,
+                cli.parts.iter(),
+            );
+            print_diff(watched_input.previous_results.as_deref(), &results);
+            watched_input.previous_results = Some(results);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn print_diff(previous_results: Option<&[PartResult]>, results: &[PartResult]) {
+    for result in results {
+        let current = outcome(result);
+        let previous = previous_results
+            .and_then(|previous| previous.iter().find(|p| p.part == result.part))
+            .map(outcome);
+        let change = match previous {
+            None => String::new(),
+            Some(previous) if previous == current => " (unchanged)".to_string(),
+            Some(previous) => format!(" (was {previous})"),
+        };
+        println!("  Part {}: {current}{change}", result.part);
+    }
+}
+
+fn outcome(result: &PartResult) -> String {
+    match &result.answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("ERROR {e}"),
+    }
+}
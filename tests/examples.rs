@@ -0,0 +1,27 @@
+use advent_of_code_2024::answers::AnswerStore;
+use advent_of_code_2024::report::run_day;
+use advent_of_code_2024::solution::{Part, get_solution, solved_days};
+use std::path::PathBuf;
+
+/// Solves every day on its example and compares the answers with `input/dayN/answers.txt`.
+#[test]
+fn examples_match_stored_answers() {
+    let mut failures = vec![];
+    for day in solved_days() {
+        let path = PathBuf::from(format!("input/day{day}/example.txt"));
+        let store = AnswerStore::load(day).unwrap();
+        let solver = get_solution(day).unwrap();
+        for result in run_day(day, solver, &path, [Part::One, Part::Two]) {
+            let actual = result.answer.map(|answer| answer.to_string());
+            let expected = store.get(&path, result.part);
+            match (&actual, expected) {
+                (Ok(actual), Some(expected)) if actual == expected => {}
+                _ => failures.push(format!(
+                    "Day {day} Part {}: expected {expected:?}, got {actual:?}",
+                    result.part
+                )),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}